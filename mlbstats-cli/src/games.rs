@@ -1,4 +1,8 @@
-use mlbstats::{games::Game, params::QueryParams, schedule::ScheduleResponse};
+use mlbstats::{
+    games::{Game, GameState},
+    hydrate::ScheduleHydrations,
+    schedule::ScheduleQuery,
+};
 use tabled::{
     settings::{
         object::{Columns, Rows},
@@ -9,7 +13,7 @@ use tabled::{
     Table, Tabled,
 };
 
-use crate::{commands::ScoreboardOpts, error::CLIError};
+use crate::{commands::ScoreboardOpts, error::CLIError, standings::try_convert_date_string};
 
 #[derive(Tabled)]
 struct ScoreLine<'a> {
@@ -43,11 +47,15 @@ fn create_score_box(game: &Game) -> Table {
 }
 
 fn game_status(game: &Game) -> String {
-    match game.status.detailed_state.as_str() {
-        "In Progress" => format!(
+    match game.status.state() {
+        GameState::InProgress => format!(
             "{} {}",
             game.linescore.inning_state, game.linescore.current_inning
         ),
+        GameState::Preview | GameState::PreGame => game
+            .start_time_in(&chrono::Local)
+            .map(|start| start.format("%-I:%M %p").to_string())
+            .unwrap_or_else(|| game.status.detailed_state.to_owned()),
         _ => game.status.detailed_state.to_owned(),
     }
 }
@@ -63,15 +71,17 @@ pub async fn scoreboard(opts: ScoreboardOpts) -> Result<(), CLIError> {
 
 async fn get_games(opts: ScoreboardOpts) -> Result<Vec<Game>, CLIError> {
     let client = mlbstats::client::Client::new();
-    let params = QueryParams {
-        date: opts.date,
-        league_id: opts.league.map(|league| vec![league]),
-        team_id: opts.team,
-        hydrate: Some("team,linescore".to_string()),
-        ..Default::default()
-    };
-    let games_response = client.get("v1/schedule", Some(&params)).await?;
+    let mut query =
+        ScheduleQuery::new().hydrate(ScheduleHydrations::new().team().linescore());
+    if let Some(date) = try_convert_date_string(opts.date)? {
+        query = query.date(date);
+    }
+    if let Some(league) = opts.league {
+        query = query.leagues(vec![league.id()]);
+    }
+    if let Some(team) = opts.team {
+        query = query.team(team.id());
+    }
 
-    let games: ScheduleResponse = games_response.json().await?;
-    Ok(games.games())
+    Ok(client.schedule(&query).await?.games())
 }
@@ -1,4 +1,4 @@
-use mlbstats::{games::GameTypes, stats::stats_leaders::StatsLeadersResponse};
+use mlbstats::{games::GameTypes, sports::Sport, stats::stats_leaders::StatsLeadersResponse};
 use tabled::{
     settings::{panel::Header, width::MinWidth},
     Table, Tabled,
@@ -47,11 +47,13 @@ async fn get_leaders(opts: LeadersOpts) -> Result<StatsLeadersResponse, CLIError
 
     let leaders_resp = client
         .stats_leaders(
+            Sport::MLB,
             vec![opts.stat.into()],
             vec![GameTypes::R],
             Some(stat_group),
             Some(opts.limit),
-            opts.league.map(|l| vec![l]),
+            opts.league.map(|l| vec![l.id()]),
+            None,
         )
         .await?;
 
@@ -1,5 +1,10 @@
 use chrono::{Datelike, NaiveDate};
-use mlbstats::{division::MLBDivision, league::MLBLeague, standings::StandingsResponse};
+use mlbstats::{
+    division::MLBDivision,
+    league::MLBLeague,
+    sports::Sport,
+    standings::{Expectation, StandingsResponse},
+};
 use tabled::{
     settings::{panel::Header, width::MinWidth},
     Table, Tabled,
@@ -15,6 +20,14 @@ struct TeamRecord {
     pub record: String,
     #[tabled(rename = "Games Back")]
     pub games_back: String,
+    #[tabled(rename = "Run Diff")]
+    pub run_differential: String,
+    /// Record expected from runs scored and allowed (PythagenPat)
+    #[tabled(rename = "Expected")]
+    pub expected: String,
+    /// Wins above the expected record
+    #[tabled(rename = "Luck")]
+    pub luck: String,
 }
 
 fn create_standings_table(division: MLBDivision, teams: Vec<TeamRecord>) -> Table {
@@ -34,7 +47,14 @@ pub async fn standings(opts: StandingsOpts) -> Result<(), CLIError> {
             .map(|team_record| TeamRecord {
                 team: team_record.team.name.clone(),
                 record: format!("{}-{}", team_record.wins, team_record.losses),
-                games_back: team_record.division_games_back.clone(),
+                games_back: team_record.division_games_back.to_string(),
+                run_differential: format!("{:+}", team_record.run_differential),
+                expected: format!(
+                    "{:.0}-{:.0}",
+                    team_record.expected_wins(Expectation::PythagenPat),
+                    team_record.expected_losses(Expectation::PythagenPat)
+                ),
+                luck: format!("{:+.1}", team_record.luck(Expectation::PythagenPat)),
             })
             .collect();
         println!(
@@ -56,7 +76,9 @@ async fn get_standings(
 
     let date = try_convert_date_string(date)?;
     let season = date.map(|date| date.year().to_string());
-    Ok(client.standings(vec![league_id], season, date).await?)
+    Ok(client
+        .standings(Sport::MLB, vec![league_id.id()], None, season, date, None, None)
+        .await?)
 }
 
 // attempts to converts an Option<String> to Option<NaiveDate>
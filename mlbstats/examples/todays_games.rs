@@ -1,11 +1,10 @@
-use mlbstats::client::Client;
-use chrono::NaiveDate;
+use mlbstats::{client::Client, schedule::ScheduleQuery};
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
     let client = Client::new();
 
-    let schedule = client.schedule(None, None, None::<NaiveDate>, None).await?;
+    let schedule = client.schedule(&ScheduleQuery::new()).await?;
 
     for date in schedule.dates {
         for game in date.games {
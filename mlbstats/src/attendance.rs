@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{meta::GameTypeMeta, types::IdNameLink};
+
+/// Whether attendance is queried for a single team or for every team in a league
+#[derive(PartialEq, Debug, Clone)]
+pub enum AttendanceScope {
+    Team(u32),
+    League(u32),
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AttendanceResponse {
+    /// Attendance for each team and season matching the query
+    pub records: Vec<AttendanceRecord>,
+    /// Totals across every record in the response
+    pub aggregate_totals: AttendanceTotals,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AttendanceRecord {
+    /// Number of dates the gates were opened
+    pub openings_total: u32,
+    pub openings_total_away: u32,
+    pub openings_total_home: u32,
+    /// Number of openings lost (e.g. postponements)
+    pub openings_total_lost: u32,
+    pub games_total: u32,
+    pub games_away_total: u32,
+    pub games_home_total: u32,
+    /// Season year
+    pub year: String,
+    pub attendance_average_away: u32,
+    pub attendance_average_home: u32,
+    pub attendance_average_ytd: u32,
+    /// Largest crowd of the season
+    pub attendance_high: u32,
+    pub attendance_high_date: String,
+    pub attendance_high_game: AttendanceGame,
+    /// Smallest crowd of the season
+    pub attendance_low: u32,
+    pub attendance_low_date: String,
+    pub attendance_low_game: AttendanceGame,
+    pub attendance_opening_average: u32,
+    pub attendance_total: u32,
+    pub attendance_total_away: u32,
+    pub attendance_total_home: u32,
+    pub game_type: GameTypeMeta,
+    pub team: IdNameLink,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl AttendanceRecord {
+    /// Returns the attendance figures for home games
+    pub fn home(&self) -> AttendanceSplit {
+        AttendanceSplit {
+            openings: self.openings_total_home,
+            games: self.games_home_total,
+            total: self.attendance_total_home,
+            average: self.attendance_average_home,
+        }
+    }
+
+    /// Returns the attendance figures for road games
+    pub fn away(&self) -> AttendanceSplit {
+        AttendanceSplit {
+            openings: self.openings_total_away,
+            games: self.games_away_total,
+            total: self.attendance_total_away,
+            average: self.attendance_average_away,
+        }
+    }
+}
+
+/// Home or away attendance figures of an AttendanceRecord
+#[derive(PartialEq, Debug, Clone)]
+pub struct AttendanceSplit {
+    pub openings: u32,
+    pub games: u32,
+    pub total: u32,
+    pub average: u32,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AttendanceGame {
+    /// Game ID
+    pub game_pk: u32,
+    /// Path to Game endpoint
+    pub link: String,
+    /// Day or night game
+    pub day_night: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct AttendanceTotals {
+    pub openings_total_away: u32,
+    pub openings_total_home: u32,
+    pub openings_total_lost: u32,
+    pub openings_total_ytd: u32,
+    pub attendance_average_away: u32,
+    pub attendance_average_home: u32,
+    pub attendance_average_ytd: u32,
+    pub attendance_high: u32,
+    pub attendance_high_date: String,
+    pub attendance_total: u32,
+    pub attendance_total_away: u32,
+    pub attendance_total_home: u32,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn deserialize_attendance() {
+        let attendance: AttendanceResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/attendance.json")).unwrap();
+        let record = &attendance.records[0];
+        assert_eq!(record.team.id, 140);
+        assert_eq!(record.attendance_high_game.game_pk, 717520);
+        assert_eq!(
+            record.home(),
+            AttendanceSplit {
+                openings: 81,
+                games: 81,
+                total: 2533044,
+                average: 31272,
+            }
+        );
+        assert_eq!(record.away().games, 81);
+        assert_eq!(attendance.aggregate_totals.attendance_total, 5067207);
+    }
+}
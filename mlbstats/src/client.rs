@@ -1,15 +1,29 @@
 #![allow(non_snake_case)]
+use crate::attendance::{AttendanceResponse, AttendanceScope};
+use crate::division::DivisionsResponse;
 use crate::error::MLBStatsError;
-use crate::games::GameTypes;
-use crate::league::MLBLeague;
+use crate::fields::Fields;
+use crate::franchise::Franchise;
+use crate::games::{GameTypes, LiveFeedResponse, Official};
+use crate::hydrate::{RosterHydrations, ScheduleHydrations, StandingsHydrations};
+use crate::jobs::{CoachesResponse, JobType, JobsResponse};
+use crate::league::LeaguesResponse;
+use crate::meta::{MetaList, MetaType};
+use crate::pagination::{paginate_pages, PageOptions, Paginated};
 use crate::params::QueryParams;
-use crate::schedule::ScheduleResponse;
-use crate::standings::StandingsResponse;
+use crate::postseason::{PostseasonBracket, PostseasonResponse};
+use crate::roster::{RosterResponse, RosterType};
+use crate::schedule::{ScheduleQuery, ScheduleResponse};
+use crate::sports::{Sport, SportsResponse};
+use crate::standings::{StandingType, StandingsResponse};
+use crate::stats::player_stats::{StatsQuery, StatsResponse};
 use crate::stats::stats_leaders::StatsLeadersResponse;
 use crate::stats::types::{LeagueLeaderTypes, StatGroup};
-use crate::teams::{MLBTeam, TeamsResponse};
+use crate::teams::{TeamRegistry, TeamsResponse};
 use chrono::Datelike;
+use futures_util::Stream;
 use reqwest::Response;
+use serde::de::DeserializeOwned;
 
 const MLB_BASE: &str = "https://statsapi.mlb.com/api/";
 #[derive(Debug, Clone)]
@@ -25,47 +39,36 @@ impl Client {
         }
     }
 
-    /// Returns the schedule of games for the current day when no date is provided. The results can be 
-    /// further narrowed down with provided parameters. 
+    /// Returns the schedule of games matching the query, a new query returns the games of the
+    /// current day
     ///
     /// Endpoint: https://statsapi.mlb.com/api/v1/schedule
     ///
-    /// Hydrations should be provided via a comma separated string (e.g. "linescore,")
+    /// Hydrations are built with ScheduleHydrations (e.g. `ScheduleHydrations::new().linescore()`)
     ///
     /// Available hydrations can be found at https://statsapi.mlb.com/api/v1/schedule\?sportId\=1\&hydrate\=hydrations
-    pub async fn schedule<D>(
-        &self,
-        league_id: Option<Vec<MLBLeague>>,
-        team_id: Option<MLBTeam>,
-        date: Option<D>,
-        hydrate: Option<String>,
-    ) -> Result<ScheduleResponse, MLBStatsError>
-    where
-        D: Datelike,
-    {
-        let params = QueryParams {
-            date: option_date_to_string(date),
-            league_id,
-            team_id,
-            hydrate,
-            ..Default::default()
-        };
-        let url = build_url("v1/schedule", Some(&params))?;
-        let resp = self
-            .client
-            .get(url)
-            .send()
+    ///
+    /// Every endpoint accepts a field selection to shrink the response, `Fields::of::<T>()` selects
+    /// only the fields deserialized by `T`
+    pub async fn schedule(&self, query: &ScheduleQuery) -> Result<ScheduleResponse, MLBStatsError> {
+        let schedule = self
+            .get("v1/schedule", Some(&query.params()))
             .await?
             .json::<ScheduleResponse>()
             .await?;
-        Ok(resp)
+        Ok(query.filter(schedule))
     }
 
-    /// Returns all MLB Teams
+    /// Returns all teams playing in the provided sport
     /// Endpoint: https://statsapi.mlb.com/api/v1/teams
-    pub async fn teams(&self) -> Result<TeamsResponse, MLBStatsError> {
+    pub async fn teams(
+        &self,
+        sport: Sport,
+        fields: Option<Fields>,
+    ) -> Result<TeamsResponse, MLBStatsError> {
         let params = QueryParams {
-            league_id: Some(vec![MLBLeague::AL, MLBLeague::NL]),
+            sport_id: sport.id(),
+            fields,
             ..Default::default()
         };
         let url = build_url("v1/teams", Some(&params))?;
@@ -78,12 +81,62 @@ impl Client {
             .await?)
     }
 
-    pub async fn game_ids(&self) -> Result<Vec<u32>, MLBStatsError> {
+    /// Loads every team of a sport into a registry for lookups by id, abbreviation, code or name.
+    /// Defaults to the current season when no season is provided.
+    /// Endpoint: https://statsapi.mlb.com/api/v1/teams
+    pub async fn team_registry(
+        &self,
+        sport: Sport,
+        season: Option<String>,
+    ) -> Result<TeamRegistry, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            season,
+            ..Default::default()
+        };
+        let teams = self.get("v1/teams", Some(&params)).await?;
+        Ok(teams.json::<TeamsResponse>().await?.into())
+    }
+
+    /// Returns every identity a team has played under, e.g. the Montreal Expos for the
+    /// Washington Nationals.
+    /// Endpoint: https://statsapi.mlb.com/api/v1/teams/{teamId}/history
+    pub async fn team_history(
+        &self,
+        team_id: u32,
+        sport: Sport,
+        fields: Option<Fields>,
+    ) -> Result<TeamsResponse, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            fields,
+            ..Default::default()
+        };
+        let history = self
+            .get(&format!("v1/teams/{}/history", team_id), Some(&params))
+            .await?;
+        Ok(history.json::<TeamsResponse>().await?)
+    }
+
+    /// Builds the franchise of a team from its history, None when the API has no history for the
+    /// team
+    pub async fn franchise(
+        &self,
+        team_id: u32,
+        sport: Sport,
+    ) -> Result<Option<Franchise>, MLBStatsError> {
+        let history = self.team_history(team_id, sport, None).await?;
+        Ok(Franchise::from_history(history.teams))
+    }
+
+    pub async fn game_ids(&self, sport: Sport) -> Result<Vec<u32>, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            fields: Some(Fields::new(&["dates", "games", "gamePk"])),
+            ..Default::default()
+        };
         let games = self
-            .client
-            .get(format!("{}{}", MLB_BASE, "v1/schedule"))
-            .query(&[("sportId", "1")])
-            .send()
+            .get("v1/schedule", Some(&params))
             .await?
             .json::<ScheduleResponse>()
             .await?
@@ -99,21 +152,30 @@ impl Client {
 
     /// Retrieve the MLB standings based on the provided parameters
     /// Endpoint: https://statsapi.mlb.com/api/v1/standings
-    /// Hydrate options: [team, league, divison, sport, conference, record(conference),
-    /// record(division), standingsOdds]
+    /// Hydrations are built with StandingsHydrations (e.g. `StandingsHydrations::new().division()`)
+    /// Defaults to the regular season standings when no standings types are provided.
+    #[allow(clippy::too_many_arguments)]
     pub async fn standings<D>(
         &self,
-        league_id: Vec<MLBLeague>,
+        sport: Sport,
+        league_id: Vec<u32>,
+        standings_types: Option<Vec<StandingType>>,
         season: Option<String>,
         date: Option<D>,
+        hydrate: Option<StandingsHydrations>,
+        fields: Option<Fields>,
     ) -> Result<StandingsResponse, MLBStatsError>
     where
         D: Datelike,
     {
         let params = QueryParams {
+            sport_id: sport.id(),
             date: option_date_to_string(date),
             league_id: Some(league_id),
+            standings_types,
             season,
+            hydrate: hydrate.map(|h| h.to_string()),
+            fields,
             ..Default::default()
         };
 
@@ -122,20 +184,25 @@ impl Client {
     }
 
     /// Endpoint: https://statsapi.mlb.com/api/v1/stats/leaders
+    #[allow(clippy::too_many_arguments)]
     pub async fn stats_leaders(
         &self,
+        sport: Sport,
         leader_categories: Vec<LeagueLeaderTypes>,
         leader_game_types: Vec<GameTypes>,
         stat_group: Option<StatGroup>,
         limit: Option<u32>,
-        league_id: Option<Vec<MLBLeague>>,
+        league_id: Option<Vec<u32>>,
+        fields: Option<Fields>,
     ) -> Result<StatsLeadersResponse, MLBStatsError> {
         let params = QueryParams {
+            sport_id: sport.id(),
             leader_categories: Some(leader_categories),
             leader_game_types: Some(leader_game_types),
             stat_group,
             limit,
             league_id,
+            fields,
             ..Default::default()
         };
 
@@ -143,6 +210,274 @@ impl Client {
         Ok(leaders.json::<StatsLeadersResponse>().await?)
     }
 
+    /// Searches player stats with the provided query. The stat line type should match the group
+    /// of the query (e.g. HittingStats for StatGroup::Hitting).
+    /// Endpoint: https://statsapi.mlb.com/api/v1/stats
+    pub async fn stats<S>(&self, query: &StatsQuery) -> Result<StatsResponse<S>, MLBStatsError>
+    where
+        S: DeserializeOwned + Default,
+    {
+        let stats = self.get("v1/stats", Some(&query.params())).await?;
+        Ok(stats.json::<StatsResponse<S>>().await?)
+    }
+
+    /// Returns every sport (level of play) available through the statsapi
+    /// Endpoint: https://statsapi.mlb.com/api/v1/sports
+    pub async fn sports(&self, fields: Option<Fields>) -> Result<SportsResponse, MLBStatsError> {
+        let params = QueryParams {
+            fields,
+            ..Default::default()
+        };
+
+        let sports = self.get("v1/sports", Some(&params)).await?;
+        Ok(sports.json::<SportsResponse>().await?)
+    }
+
+    /// Returns the leagues belonging to the provided sport
+    /// Endpoint: https://statsapi.mlb.com/api/v1/league
+    pub async fn leagues(
+        &self,
+        sport: Sport,
+        fields: Option<Fields>,
+    ) -> Result<LeaguesResponse, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            fields,
+            ..Default::default()
+        };
+
+        let leagues = self.get("v1/league", Some(&params)).await?;
+        Ok(leagues.json::<LeaguesResponse>().await?)
+    }
+
+    /// Returns the postseason games of a season grouped into series. Defaults to the current
+    /// season and every round when no season or series types are provided.
+    /// Endpoint: https://statsapi.mlb.com/api/v1/schedule/postseason/series
+    pub async fn postseason(
+        &self,
+        sport: Sport,
+        season: Option<String>,
+        series_types: Option<Vec<GameTypes>>,
+        fields: Option<Fields>,
+    ) -> Result<PostseasonBracket, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            season,
+            game_types: series_types,
+            hydrate: Some(ScheduleHydrations::new().team().to_string()),
+            fields,
+            ..Default::default()
+        };
+
+        let postseason = self
+            .get("v1/schedule/postseason/series", Some(&params))
+            .await?
+            .json::<PostseasonResponse>()
+            .await?;
+        Ok(postseason.into())
+    }
+
+    /// Returns season attendance for a single team or every team in a league
+    /// Endpoint: https://statsapi.mlb.com/api/v1/attendance
+    pub async fn attendance(
+        &self,
+        scope: AttendanceScope,
+        sport: Sport,
+        season: Option<String>,
+        game_type: Option<GameTypes>,
+        fields: Option<Fields>,
+    ) -> Result<AttendanceResponse, MLBStatsError> {
+        let (team_id, league_id) = match scope {
+            AttendanceScope::Team(id) => (Some(vec![id]), None),
+            AttendanceScope::League(id) => (None, Some(vec![id])),
+        };
+        let params = QueryParams {
+            team_id,
+            league_id,
+            sport_id: sport.id(),
+            season,
+            game_type: game_type.map(|game_type| vec![game_type]),
+            fields,
+            ..Default::default()
+        };
+
+        let attendance = self.get("v1/attendance", Some(&params)).await?;
+        Ok(attendance.json::<AttendanceResponse>().await?)
+    }
+
+    /// Returns the divisions for the provided sport, optionally narrowed to a league. Passing a
+    /// season returns the divisions as they were aligned that year (e.g. 1993 has no Central).
+    /// Endpoint: https://statsapi.mlb.com/api/v1/divisions
+    pub async fn divisions(
+        &self,
+        league_id: Option<u32>,
+        sport: Sport,
+        season: Option<String>,
+        fields: Option<Fields>,
+    ) -> Result<DivisionsResponse, MLBStatsError> {
+        let params = QueryParams {
+            league_id: league_id.map(|id| vec![id]),
+            sport_id: sport.id(),
+            season,
+            fields,
+            ..Default::default()
+        };
+
+        let divisions = self.get("v1/divisions", Some(&params)).await?;
+        Ok(divisions.json::<DivisionsResponse>().await?)
+    }
+
+    /// Returns the typed lookup list for the provided meta type. The returned MetaList variant
+    /// always matches the requested MetaType.
+    /// Endpoint: https://statsapi.mlb.com/api/v1/{type} (e.g. v1/gameTypes)
+    pub async fn meta(
+        &self,
+        meta_type: MetaType,
+        fields: Option<Fields>,
+    ) -> Result<MetaList, MLBStatsError> {
+        let params = QueryParams {
+            fields,
+            ..Default::default()
+        };
+
+        let resp = self.get(&meta_type.path(), Some(&params)).await?;
+        Ok(match meta_type {
+            MetaType::GameTypes => MetaList::GameTypes(resp.json().await?),
+            MetaType::GameStatus => MetaList::GameStatus(resp.json().await?),
+            MetaType::StatTypes => MetaList::StatTypes(resp.json().await?),
+            MetaType::StatGroups => MetaList::StatGroups(resp.json().await?),
+            MetaType::LeagueLeaderTypes => MetaList::LeagueLeaderTypes(resp.json().await?),
+            MetaType::HitTrajectories => MetaList::HitTrajectories(resp.json().await?),
+            MetaType::PitchTypes => MetaList::PitchTypes(resp.json().await?),
+            MetaType::PitchCodes => MetaList::PitchCodes(resp.json().await?),
+            MetaType::Positions => MetaList::Positions(resp.json().await?),
+            MetaType::WindDirection => MetaList::WindDirection(resp.json().await?),
+            MetaType::SkyDescriptions => MetaList::SkyDescriptions(resp.json().await?),
+            MetaType::EventTypes => MetaList::EventTypes(resp.json().await?),
+            MetaType::StandingsTypes => MetaList::StandingsTypes(resp.json().await?),
+            MetaType::RosterTypes => MetaList::RosterTypes(resp.json().await?),
+        })
+    }
+
+    /// Returns the people holding the provided job (e.g. umpires) on the given date, defaults to
+    /// today when no date is provided
+    /// Endpoint: https://statsapi.mlb.com/api/v1/jobs/{type} (e.g. v1/jobs/umpires)
+    pub async fn jobs<D>(
+        &self,
+        job_type: JobType,
+        sport: Sport,
+        date: Option<D>,
+        fields: Option<Fields>,
+    ) -> Result<JobsResponse, MLBStatsError>
+    where
+        D: Datelike,
+    {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            date: option_date_to_string(date),
+            fields,
+            ..Default::default()
+        };
+
+        let jobs = self.get(&job_type.path(), Some(&params)).await?;
+        Ok(jobs.json::<JobsResponse>().await?)
+    }
+
+    /// Returns the coaching staff of a team, defaults to the current season
+    /// Endpoint: https://statsapi.mlb.com/api/v1/teams/{id}/coaches
+    pub async fn coaches(
+        &self,
+        team_id: u32,
+        season: Option<String>,
+        fields: Option<Fields>,
+    ) -> Result<CoachesResponse, MLBStatsError> {
+        let params = QueryParams {
+            season,
+            fields,
+            ..Default::default()
+        };
+
+        let coaches = self
+            .get(&format!("v1/teams/{}/coaches", team_id), Some(&params))
+            .await?;
+        Ok(coaches.json::<CoachesResponse>().await?)
+    }
+
+    /// Returns the roster of a team, defaults to the active roster of the current season. People
+    /// are hydrated so their handedness and primary position are included.
+    /// Endpoint: https://statsapi.mlb.com/api/v1/teams/{id}/roster
+    pub async fn roster(
+        &self,
+        team_id: u32,
+        sport: Sport,
+        roster_type: Option<RosterType>,
+        season: Option<String>,
+        fields: Option<Fields>,
+    ) -> Result<RosterResponse, MLBStatsError> {
+        let params = QueryParams {
+            sport_id: sport.id(),
+            roster_type,
+            season,
+            hydrate: Some(RosterHydrations::new().person().to_string()),
+            fields,
+            ..Default::default()
+        };
+
+        let roster = self
+            .get(&format!("v1/teams/{}/roster", team_id), Some(&params))
+            .await?;
+        Ok(roster.json::<RosterResponse>().await?)
+    }
+
+    /// Returns the umpires working a game from the live game feed. Only the officials are
+    /// requested since the full feed is several megabytes.
+    /// Endpoint: https://statsapi.mlb.com/api/v1.1/game/{gamePk}/feed/live
+    pub async fn game_officials(&self, game_pk: u32) -> Result<Vec<Official>, MLBStatsError> {
+        let params = QueryParams {
+            fields: Some(Fields::of::<LiveFeedResponse>()),
+            ..Default::default()
+        };
+        let feed = self
+            .get(&format!("v1.1/game/{}/feed/live", game_pk), Some(&params))
+            .await?
+            .json::<LiveFeedResponse>()
+            .await?;
+        Ok(feed.live_data.boxscore.officials)
+    }
+
+    /// Walks the offset of a limit/offset endpoint until every result has been returned, yielding
+    /// the individual items of each page. The limit and offset of the provided params are managed
+    /// by the stream, an offset already set is used as the starting point.
+    ///
+    /// Stat search: `client.paginate::<StatsResponse<HittingStats>>("v1/stats", query.params(), options)`
+    pub fn paginate<P>(
+        &self,
+        path: &str,
+        params: QueryParams,
+        options: PageOptions,
+    ) -> impl Stream<Item = Result<P::Item, MLBStatsError>>
+    where
+        P: Paginated,
+    {
+        let client = self.clone();
+        let path = path.to_string();
+        let start = params.offset.unwrap_or(0);
+
+        paginate_pages(start, options, move |offset, limit| {
+            let client = client.clone();
+            let path = path.clone();
+            let params = QueryParams {
+                offset: Some(offset),
+                limit: Some(limit),
+                ..params.clone()
+            };
+            async move {
+                let page = client.get(&path, Some(&params)).await?.json::<P>().await?;
+                Ok(page.into_items())
+            }
+        })
+    }
+
     /// Makes requests to an statsapi endpoint with the provided path and parameters
     ///
     /// hydrate the teams and linescores of the games in the current day's schedule
@@ -164,7 +499,7 @@ impl Default for Client {
 }
 
 // Converts and Option<Datelike> -> Option<String> for api usage
-fn option_date_to_string<D>(date: Option<D>) -> Option<String>
+pub(crate) fn option_date_to_string<D>(date: Option<D>) -> Option<String>
 where
     D: Datelike,
 {
@@ -179,7 +514,7 @@ fn build_url(path: &str, params: Option<&QueryParams>) -> Result<String, MLBStat
 
     let params = serde_url_params::to_string(params.unwrap())?;
     if params.is_empty() {
-        return Ok(format!("{}{}", MLB_BASE, path));
+        Ok(format!("{}{}", MLB_BASE, path))
     } else {
         Ok(format!("{}{}?{}", MLB_BASE, path, params))
     }
@@ -206,7 +541,7 @@ mod test {
 
         // with a param
         let params = QueryParams {
-            league_id: Some(vec![MLBLeague::AL]),
+            league_id: Some(vec![MLBLeague::AL.id()]),
             ..Default::default()
         };
         let url = build_url("v1/standings", Some(&params))?;
@@ -216,7 +551,7 @@ mod test {
         );
 
         let params = QueryParams {
-            league_id: Some(vec![MLBLeague::AL, MLBLeague::NL]),
+            league_id: Some(vec![MLBLeague::AL.id(), MLBLeague::NL.id()]),
             ..Default::default()
         };
 
@@ -226,13 +561,30 @@ mod test {
             "https://statsapi.mlb.com/api/v1/standings?leagueId=103&leagueId=104&sportId=1"
         );
 
+        let params = QueryParams {
+            sport_id: Sport::AAA.id(),
+            ..Default::default()
+        };
+        let url = build_url("v1/league", Some(&params))?;
+        assert_eq!(url, "https://statsapi.mlb.com/api/v1/league?sportId=11");
+
+        let params = QueryParams {
+            fields: Some(Fields::new(&["dates", "games", "gamePk"])),
+            ..Default::default()
+        };
+        let url = build_url("v1/schedule", Some(&params))?;
+        assert_eq!(
+            url,
+            "https://statsapi.mlb.com/api/v1/schedule?fields=dates%2Cgames%2CgamePk&sportId=1"
+        );
+
         Ok(())
     }
 
     #[tokio::test]
     async fn teams() {
         let client = Client::new();
-        let teams = client.teams().await;
+        let teams = client.teams(Sport::MLB, None).await;
         assert!(teams.unwrap().teams.len() == 30);
     }
 
@@ -240,13 +592,29 @@ mod test {
     async fn standings() {
         let client = Client::new();
         let standings = client
-            .standings(vec![MLBLeague::AL], None, None::<NaiveDate>)
+            .standings(
+                Sport::MLB,
+                vec![MLBLeague::AL.id()],
+                None,
+                None,
+                None::<NaiveDate>,
+                None,
+                None,
+            )
             .await;
         dbg!(&standings);
         assert!(standings.is_ok());
 
         let standings = client
-            .standings(vec![MLBLeague::AL, MLBLeague::NL], None, None::<NaiveDate>)
+            .standings(
+                Sport::MLB,
+                vec![MLBLeague::AL.id(), MLBLeague::NL.id()],
+                Some(vec![StandingType::RegularSeason, StandingType::WildCard]),
+                None,
+                None::<NaiveDate>,
+                None,
+                None,
+            )
             .await;
         dbg!(&standings);
         assert!(standings.is_ok());
@@ -255,13 +623,12 @@ mod test {
     #[tokio::test]
     async fn schedule() {
         let client = Client::new();
-        let schedule = client.schedule(None, None, None::<NaiveDate>, None).await;
+        let schedule = client.schedule(&ScheduleQuery::new()).await;
         assert!(schedule.is_ok());
 
-        let schedule = client
-            .schedule(None, None, Some(NaiveDate::from_ymd(2022, 04, 12)), None)
-            .await;
+        let query = ScheduleQuery::new().date(NaiveDate::from_ymd_opt(2022, 4, 12).unwrap());
+        let schedule = client.schedule(&query).await;
         assert!(schedule.is_ok());
-        assert!(schedule.unwrap().dates.len() != 0);
+        assert!(!schedule.unwrap().dates.is_empty());
     }
 }
@@ -0,0 +1,342 @@
+use std::collections::HashMap;
+
+use crate::{
+    games::{GameState, GameTypes},
+    playoffs::{HeadToHead, PlayoffPicture, WILD_CARDS},
+    schedule::ScheduleResponse,
+    standings::{StandingsResponse, TeamRecord},
+};
+
+/// Regular season games left for each team and between each pair of teams
+#[derive(Default, Debug, Clone)]
+pub struct RemainingGames {
+    matchups: Vec<(u32, u32)>,
+    games: HashMap<u32, u32>,
+    between: HashMap<(u32, u32), u32>,
+}
+
+impl RemainingGames {
+    /// Counts the regular season games in a schedule that haven't finished. Postponed and
+    /// cancelled games are skipped since a rescheduled game is listed separately.
+    pub fn from_schedule(schedule: &ScheduleResponse) -> Self {
+        let mut remaining = RemainingGames::default();
+        for game in schedule.games() {
+            let state = game.status.state();
+            if game.game_type != GameTypes::R
+                || state.is_final()
+                || matches!(state, GameState::Postponed(_) | GameState::Cancelled(_))
+            {
+                continue;
+            }
+            remaining.add_game(game.teams.home.team.id, game.teams.away.team.id);
+        }
+        remaining
+    }
+
+    pub fn add_game(&mut self, home_id: u32, away_id: u32) {
+        self.matchups.push((home_id, away_id));
+        *self.games.entry(home_id).or_default() += 1;
+        *self.games.entry(away_id).or_default() += 1;
+        *self.between.entry(Self::key(home_id, away_id)).or_default() += 1;
+    }
+
+    /// Home and away team ids of each game left, in the order they were added
+    pub fn matchups(&self) -> &[(u32, u32)] {
+        &self.matchups
+    }
+
+    pub fn games(&self, team_id: u32) -> u32 {
+        self.games.get(&team_id).copied().unwrap_or_default()
+    }
+
+    /// Games left between two teams
+    pub fn between(&self, team_id: u32, opponent_id: u32) -> u32 {
+        self.between
+            .get(&Self::key(team_id, opponent_id))
+            .copied()
+            .unwrap_or_default()
+    }
+
+    fn key(a: u32, b: u32) -> (u32, u32) {
+        (a.min(b), a.max(b))
+    }
+}
+
+/// Magic and elimination numbers of a team. A magic number is the combination of wins by the
+/// team and losses by its rivals needed to clinch, 0 once clinched. An elimination number is the
+/// combination of losses by the team and wins by the teams ahead of it that eliminates it, 0 once
+/// eliminated.
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct ClinchNumbers {
+    pub team_id: u32,
+    pub division_magic_number: u32,
+    pub division_elimination_number: u32,
+    /// None for division leaders, who aren't part of the wild card race
+    pub wild_card_magic_number: Option<u32>,
+    pub wild_card_elimination_number: Option<u32>,
+}
+
+impl ClinchNumbers {
+    pub fn clinched_division(&self) -> bool {
+        self.division_magic_number == 0
+    }
+
+    pub fn eliminated_from_division(&self) -> bool {
+        self.division_elimination_number == 0
+    }
+
+    pub fn clinched_wild_card(&self) -> bool {
+        self.wild_card_magic_number == Some(0)
+    }
+
+    pub fn eliminated_from_wild_card(&self) -> bool {
+        self.wild_card_elimination_number == Some(0)
+    }
+}
+
+/// Computes the magic and elimination numbers of every team in regular season standings by
+/// division from the games each team has left.
+///
+/// A tiebreaker is only credited once a team has clinched the season series, counting the games
+/// left between the two teams. Until then a tie is treated as unresolved, so numbers are one
+/// higher than they would be for the team that ends up with the tiebreaker. Wild card numbers
+/// are computed between the teams not leading their division.
+pub fn clinch_numbers(
+    standings: &StandingsResponse,
+    remaining: &RemainingGames,
+    head_to_head: &HeadToHead,
+) -> Vec<ClinchNumbers> {
+    let race = Race {
+        remaining,
+        head_to_head,
+    };
+
+    let mut league_ids: Vec<u32> = standings.records.iter().map(|r| r.league.id).collect();
+    league_ids.sort_unstable();
+    league_ids.dedup();
+
+    let mut numbers = vec![];
+    for league_id in league_ids {
+        let leaders: Vec<u32> = PlayoffPicture::new(standings, league_id, head_to_head)
+            .division_winners()
+            .map(|seed| seed.team.team.id)
+            .collect();
+        let divisions: Vec<&[TeamRecord]> = standings
+            .records
+            .iter()
+            .filter(|division| division.league.id == league_id)
+            .map(|division| division.team_records.as_slice())
+            .collect();
+        let contenders: Vec<&TeamRecord> = divisions
+            .iter()
+            .flat_map(|division| division.iter())
+            .filter(|team| !leaders.contains(&team.team.id))
+            .collect();
+
+        for division in &divisions {
+            for team in division.iter() {
+                let rivals: Vec<&TeamRecord> = division
+                    .iter()
+                    .filter(|t| t.team.id != team.team.id)
+                    .collect();
+                let (wild_card_magic_number, wild_card_elimination_number) =
+                    if leaders.contains(&team.team.id) {
+                        (None, None)
+                    } else {
+                        let rivals: Vec<&TeamRecord> = contenders
+                            .iter()
+                            .copied()
+                            .filter(|t| t.team.id != team.team.id)
+                            .collect();
+                        (
+                            Some(race.magic_number(team, &rivals, WILD_CARDS)),
+                            Some(race.elimination_number(team, &rivals, WILD_CARDS)),
+                        )
+                    };
+
+                numbers.push(ClinchNumbers {
+                    team_id: team.team.id,
+                    division_magic_number: race.magic_number(team, &rivals, 1),
+                    division_elimination_number: race.elimination_number(team, &rivals, 1),
+                    wild_card_magic_number,
+                    wild_card_elimination_number,
+                });
+            }
+        }
+    }
+    numbers
+}
+
+struct Race<'a> {
+    remaining: &'a RemainingGames,
+    head_to_head: &'a HeadToHead,
+}
+
+impl<'a> Race<'a> {
+    fn max_wins(&self, team: &TeamRecord) -> u32 {
+        team.wins + self.remaining.games(team.team.id)
+    }
+
+    // Whether a team is guaranteed to win the season series, and with it the tiebreaker
+    fn tiebreaker(&self, team: &TeamRecord, opponent: &TeamRecord) -> u32 {
+        let (wins, losses) = self.head_to_head.record(team.team.id, opponent.team.id);
+        let left = self.remaining.between(team.team.id, opponent.team.id);
+        u32::from(wins > losses + left)
+    }
+
+    // Wins by the team plus losses by the rival needed to finish ahead of the rival
+    fn magic_number_over(&self, team: &TeamRecord, rival: &TeamRecord) -> u32 {
+        (self.max_wins(rival) + 1).saturating_sub(team.wins + self.tiebreaker(team, rival))
+    }
+
+    // Clinching a spot means finishing ahead of every rival except the spots - 1 best
+    fn magic_number(&self, team: &TeamRecord, rivals: &[&TeamRecord], spots: usize) -> u32 {
+        let mut numbers: Vec<u32> = rivals
+            .iter()
+            .map(|rival| self.magic_number_over(team, rival))
+            .collect();
+        numbers.sort_unstable_by(|a, b| b.cmp(a));
+        numbers.get(spots - 1).copied().unwrap_or_default()
+    }
+
+    // Elimination means spots rivals are guaranteed to finish ahead of the team
+    fn elimination_number(&self, team: &TeamRecord, rivals: &[&TeamRecord], spots: usize) -> u32 {
+        let mut numbers: Vec<u32> = rivals
+            .iter()
+            .map(|rival| self.magic_number_over(rival, team))
+            .collect();
+        numbers.sort_unstable();
+        numbers
+            .get(spots - 1)
+            .copied()
+            .unwrap_or(self.max_wins(team) + 1)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::standings::ClinchNumber;
+
+    fn standings() -> StandingsResponse {
+        serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap()
+    }
+
+    fn team(numbers: &[ClinchNumbers], team_id: u32) -> &ClinchNumbers {
+        numbers.iter().find(|n| n.team_id == team_id).unwrap()
+    }
+
+    fn series(winner: u32, loser: u32, wins: u32, losses: u32) -> HeadToHead {
+        let mut head_to_head = HeadToHead::default();
+        (0..wins).for_each(|_| head_to_head.add_game(winner, loser));
+        (0..losses).for_each(|_| head_to_head.add_game(loser, winner));
+        head_to_head
+    }
+
+    #[test]
+    fn final_standings_match_api() {
+        let standings = standings();
+        // HOU won the season series with TEX 9-4 for the AL West
+        let numbers = clinch_numbers(
+            &standings,
+            &RemainingGames::default(),
+            &series(117, 140, 9, 4),
+        );
+        assert_eq!(numbers.len(), 15);
+
+        for record in standings.records.iter().flat_map(|r| r.team_records.iter()) {
+            let computed = team(&numbers, record.team.id);
+            if record.elimination_number == Some(ClinchNumber::Eliminated) {
+                assert!(computed.eliminated_from_division(), "{}", record.team.id);
+            }
+            if record.wild_card_elimination_number == Some(ClinchNumber::Eliminated) {
+                assert!(computed.eliminated_from_wild_card(), "{}", record.team.id);
+            }
+            if record.clinched {
+                assert!(
+                    computed.clinched_division() || computed.clinched_wild_card(),
+                    "{}",
+                    record.team.id
+                );
+            }
+        }
+
+        // division leaders are out of the wild card race
+        assert_eq!(team(&numbers, 110).wild_card_magic_number, None);
+        // TB finished 10 games clear of the last wild card but 2 behind BAL
+        assert!(team(&numbers, 139).clinched_wild_card());
+        assert!(team(&numbers, 139).eliminated_from_division());
+        // SEA finished a game behind TOR for the last spot
+        assert_eq!(team(&numbers, 136).wild_card_elimination_number, Some(0));
+        assert_eq!(team(&numbers, 141).wild_card_elimination_number, Some(2));
+    }
+
+    #[test]
+    fn tiebreakers() {
+        // without the season series the AL West tie is unresolved
+        let standings = standings();
+        let numbers = clinch_numbers(
+            &standings,
+            &RemainingGames::default(),
+            &HeadToHead::default(),
+        );
+        assert_eq!(team(&numbers, 140).division_elimination_number, 1);
+        assert_eq!(team(&numbers, 117).division_magic_number, 1);
+
+        let numbers = clinch_numbers(
+            &standings,
+            &RemainingGames::default(),
+            &series(117, 140, 9, 4),
+        );
+        assert_eq!(team(&numbers, 140).division_elimination_number, 0);
+        assert!(team(&numbers, 117).clinched_division());
+
+        // a 6-5 series isn't decided with two games left between the teams
+        let mut remaining = RemainingGames::default();
+        remaining.add_game(117, 140);
+        remaining.add_game(140, 117);
+        let numbers = clinch_numbers(&standings, &remaining, &series(117, 140, 6, 5));
+        assert_eq!(team(&numbers, 117).division_magic_number, 3);
+        // 8-3 is decided either way
+        let numbers = clinch_numbers(&standings, &remaining, &series(117, 140, 8, 3));
+        assert_eq!(team(&numbers, 117).division_magic_number, 2);
+        assert_eq!(team(&numbers, 140).division_elimination_number, 2);
+    }
+
+    #[test]
+    fn remaining_games() {
+        // with 6 games left SEA (88-74) could still catch HOU and TEX (90-72)
+        let standings = standings();
+        let mut remaining = RemainingGames::default();
+        for _ in 0..3 {
+            remaining.add_game(136, 117);
+            remaining.add_game(136, 108);
+        }
+        assert_eq!(remaining.games(136), 6);
+        assert_eq!(remaining.between(117, 136), 3);
+        assert_eq!(remaining.matchups().len(), 6);
+
+        let numbers = clinch_numbers(&standings, &remaining, &HeadToHead::default());
+        let mariners = team(&numbers, 136);
+        assert_eq!(mariners.division_elimination_number, 5);
+        // TB is already ahead, TEX and TOR need 5 and 6
+        assert_eq!(mariners.wild_card_elimination_number, Some(6));
+        // HOU needs to finish ahead of SEA and TEX, who has no games left
+        assert_eq!(team(&numbers, 117).division_magic_number, 5);
+    }
+
+    #[test]
+    fn remaining_games_from_schedule() {
+        let schedule: ScheduleResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/schedule_live.json")).unwrap();
+        let remaining = RemainingGames::from_schedule(&schedule);
+        assert_eq!(remaining.games(140), 1);
+        assert_eq!(remaining.between(141, 140), 1);
+
+        let schedule: ScheduleResponse = serde_json::from_str(include_str!(
+            "../tests/fixtures/schedule_double_header.json"
+        ))
+        .unwrap();
+        assert_eq!(RemainingGames::from_schedule(&schedule).games(140), 0);
+    }
+}
@@ -1,15 +1,26 @@
 use std::str::FromStr;
-use serde::Deserialize;
-use crate::error::MLBStatsError;
+use serde::{Deserialize, Serialize};
+use crate::{error::MLBStatsError, league::MLBLeague, types::IdNameLink};
 
-#[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
 pub struct DivisionsResponse {
     pub divisions: Vec<Division>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl DivisionsResponse {
+    /// Returns the fetched division record matching the provided MLBDivision, if present
+    pub fn find(&self, division: &MLBDivision) -> Option<&Division> {
+        self.divisions.iter().find(|d| d.id == division.id())
+    }
 }
 
-#[derive(Deserialize, Debug, Clone)]
-#[serde(rename_all = "camelCase")]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
 pub struct Division {
     /// Unique id for the division
     pub id: u32,
@@ -25,6 +36,38 @@ pub struct Division {
 
     /// Abbreviation of divison (e.g. ALW)
     pub abbreviation: String,
+
+    /// Path to division endpoint
+    pub link: String,
+
+    /// League the division belongs to
+    pub league: IdNameLink,
+
+    /// Sport the division belongs to
+    pub sport: IdNameLink,
+
+    /// Whether the division winner can be passed by a wild card team for seeding
+    pub has_wildcard: bool,
+
+    pub sort_order: u32,
+
+    /// Number of teams from the division that qualify for the playoffs
+    pub num_playoff_teams: Option<u32>,
+
+    /// Whether the division is currently active
+    pub active: bool,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl Division {
+    /// Converts the fetched division into an MLBDivision. Returns None for divisions outside of
+    /// MLB (e.g. minor league divisions) rather than erroring.
+    pub fn mlb_division(&self) -> Option<MLBDivision> {
+        MLBDivision::try_from(self.id).ok()
+    }
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -62,6 +105,31 @@ impl MLBDivision {
         }
     }
 
+    /// Returns the league the division belongs to
+    pub fn league(&self) -> MLBLeague {
+        match self {
+            MLBDivision::ALCentral | MLBDivision::ALEast | MLBDivision::ALWest => MLBLeague::AL,
+            MLBDivision::NLCentral | MLBDivision::NLEast | MLBDivision::NLWest => MLBLeague::NL,
+        }
+    }
+
+    /// Returns the divisions of a league for a given season. Leagues had no divisions before
+    /// 1969 and were split into East and West until the Central divisions were added in 1994.
+    pub fn for_season(league: &MLBLeague, season: u32) -> Vec<MLBDivision> {
+        let divisions = match league {
+            MLBLeague::AL => [MLBDivision::ALEast, MLBDivision::ALCentral, MLBDivision::ALWest],
+            MLBLeague::NL => [MLBDivision::NLEast, MLBDivision::NLCentral, MLBDivision::NLWest],
+        };
+        match season {
+            0..=1968 => vec![],
+            1969..=1993 => divisions
+                .into_iter()
+                .filter(|d| !matches!(d, MLBDivision::ALCentral | MLBDivision::NLCentral))
+                .collect(),
+            _ => divisions.to_vec(),
+        }
+    }
+
     /// Returns the short name of the division (e.g. AL West)
     pub fn short_name(&self) -> String {
         match self {
@@ -142,5 +210,21 @@ mod test {
 
         let alc: u32 = MLBDivision::ALCentral.into();
         assert_eq!(alc, 202);
+
+        let division = Division {
+            id: 5000,
+            ..Default::default()
+        };
+        assert!(division.mlb_division().is_none());
+    }
+
+    #[test]
+    fn historical_divisions() {
+        assert!(MLBDivision::for_season(&MLBLeague::AL, 1968).is_empty());
+        assert_eq!(
+            MLBDivision::for_season(&MLBLeague::NL, 1990),
+            vec![MLBDivision::NLEast, MLBDivision::NLWest]
+        );
+        assert_eq!(MLBDivision::for_season(&MLBLeague::AL, 1994).len(), 3);
     }
 }
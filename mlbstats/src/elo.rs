@@ -0,0 +1,495 @@
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+use chrono::NaiveDate;
+
+use crate::{
+    games::{Game, GameTypes},
+    schedule::ScheduleResponse,
+};
+
+/// Parameters of the rating system, the defaults follow the usual MLB calibration: ratings start
+/// at 1500, the home team plays 24 points better and a third of each rating's distance from the
+/// mean is removed between seasons
+#[derive(PartialEq, Debug, Clone)]
+pub struct EloOptions {
+    pub initial_rating: f64,
+    /// Rating change of a game before the margin of victory multiplier
+    pub k: f64,
+    /// Points added to the rating of the home team when computing expectations
+    pub home_advantage: f64,
+    /// Share of the distance to the initial rating removed at the start of each season, between
+    /// 0 and 1
+    pub season_regression: f64,
+    /// Whether the rating change is scaled by the margin of victory
+    pub margin_of_victory: bool,
+}
+
+impl Default for EloOptions {
+    fn default() -> Self {
+        Self {
+            initial_rating: 1500.0,
+            k: 4.0,
+            home_advantage: 24.0,
+            season_regression: 1.0 / 3.0,
+            margin_of_victory: true,
+        }
+    }
+}
+
+/// Final score of a game to count, see EloRatings::add_game
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct GameResult {
+    pub game_pk: u32,
+    /// Season the game counts towards, ratings are regressed before the first game of a season
+    pub season: Option<u32>,
+    pub date: Option<NaiveDate>,
+    pub home_id: u32,
+    pub away_id: u32,
+    pub home_score: u8,
+    pub away_score: u8,
+}
+
+impl GameResult {
+    /// Result of a game from a schedule, None if the game has no score
+    pub fn from_game(game: &Game) -> Option<Self> {
+        let (home, away) = (&game.teams.home, &game.teams.away);
+        Some(Self {
+            game_pk: game.game_pk,
+            season: game.season.parse().ok(),
+            date: game
+                .official_date
+                .or(game.game_date.map(|date| date.date_naive())),
+            home_id: home.team.id,
+            away_id: away.team.id,
+            home_score: home.score?,
+            away_score: away.score?,
+        })
+    }
+}
+
+/// Ratings of both teams before and after a game
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct EloGame {
+    pub game_pk: u32,
+    pub season: Option<u32>,
+    pub date: Option<NaiveDate>,
+    pub home_id: u32,
+    pub away_id: u32,
+    pub home_score: u8,
+    pub away_score: u8,
+    /// Probability of the home team winning given the ratings before the game
+    pub home_win_probability: f64,
+    pub home_rating_before: f64,
+    pub away_rating_before: f64,
+    pub home_rating_after: f64,
+    pub away_rating_after: f64,
+}
+
+impl EloGame {
+    /// Rating of a team after the game, None if the team didn't play in it
+    pub fn rating_after(&self, team_id: u32) -> Option<f64> {
+        if team_id == self.home_id {
+            Some(self.home_rating_after)
+        } else if team_id == self.away_id {
+            Some(self.away_rating_after)
+        } else {
+            None
+        }
+    }
+}
+
+/// Elo ratings maintained by replaying final games in the order they were played. Regular season
+/// and postseason games count, spring training and exhibitions are skipped. Replaying a game that
+/// was already counted (e.g. schedules with overlapping date ranges) has no effect.
+#[derive(Default, Debug, Clone)]
+pub struct EloRatings {
+    options: EloOptions,
+    ratings: HashMap<u32, f64>,
+    history: Vec<EloGame>,
+    played: HashSet<u32>,
+    season: Option<u32>,
+}
+
+impl EloRatings {
+    pub fn new(options: EloOptions) -> Self {
+        Self {
+            options,
+            ..Default::default()
+        }
+    }
+
+    pub fn options(&self) -> &EloOptions {
+        &self.options
+    }
+
+    /// Replays the final games of a schedule (e.g. from ScheduleQuery with a start and end date)
+    /// ordered by start time. Schedules need to be replayed in chronological order.
+    pub fn replay(&mut self, schedule: &ScheduleResponse) {
+        let mut games: Vec<Game> = schedule
+            .games()
+            .into_iter()
+            .filter(|game| {
+                matches!(
+                    game.game_type,
+                    GameTypes::R | GameTypes::F | GameTypes::D | GameTypes::L | GameTypes::W
+                ) && game.status.state().is_final()
+            })
+            .collect();
+        games.sort_by_key(|game| (game.official_date, game.game_date, game.game_pk));
+
+        for result in games.iter().filter_map(GameResult::from_game) {
+            self.add_game(result);
+        }
+    }
+
+    /// Counts a single game, ratings are regressed first when the game is the first of a new
+    /// season. Returns None if the game was already counted.
+    pub fn add_game(&mut self, game: GameResult) -> Option<&EloGame> {
+        let GameResult {
+            game_pk,
+            season,
+            date,
+            home_id,
+            away_id,
+            home_score,
+            away_score,
+        } = game;
+        if !self.played.insert(game_pk) {
+            return None;
+        }
+        if let Some(season) = season {
+            if self.season.is_some_and(|current| season > current) {
+                self.regress();
+            }
+            self.season = Some(self.season.map_or(season, |current| current.max(season)));
+        }
+
+        let home_rating_before = self.rating(home_id);
+        let away_rating_before = self.rating(away_id);
+        let home_win_probability = self.expected(home_rating_before, away_rating_before);
+
+        let result = match home_score.cmp(&away_score) {
+            Ordering::Greater => 1.0,
+            Ordering::Less => 0.0,
+            Ordering::Equal => 0.5,
+        };
+        let multiplier = self.multiplier(
+            home_rating_before,
+            away_rating_before,
+            home_score,
+            away_score,
+        );
+        let change = self.options.k * multiplier * (result - home_win_probability);
+        self.ratings.insert(home_id, home_rating_before + change);
+        self.ratings.insert(away_id, away_rating_before - change);
+
+        self.history.push(EloGame {
+            game_pk,
+            season,
+            date,
+            home_id,
+            away_id,
+            home_score,
+            away_score,
+            home_win_probability,
+            home_rating_before,
+            away_rating_before,
+            home_rating_after: home_rating_before + change,
+            away_rating_after: away_rating_before - change,
+        });
+        self.history.last()
+    }
+
+    /// Current rating of a team, the initial rating for teams that haven't played
+    pub fn rating(&self, team_id: u32) -> f64 {
+        self.ratings
+            .get(&team_id)
+            .copied()
+            .unwrap_or(self.options.initial_rating)
+    }
+
+    /// Current ratings ordered from best to worst
+    pub fn ratings(&self) -> Vec<(u32, f64)> {
+        let mut ratings: Vec<(u32, f64)> = self.ratings.iter().map(|(&id, &r)| (id, r)).collect();
+        ratings.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        ratings
+    }
+
+    /// Every game counted, in the order it was counted
+    pub fn history(&self) -> &[EloGame] {
+        &self.history
+    }
+
+    /// Rating of a team after each of its games
+    pub fn team_history(&self, team_id: u32) -> Vec<(Option<NaiveDate>, f64)> {
+        self.history
+            .iter()
+            .filter_map(|game| game.rating_after(team_id).map(|rating| (game.date, rating)))
+            .collect()
+    }
+
+    /// Probability of the home team beating the away team with the current ratings
+    pub fn win_probability(&self, home_id: u32, away_id: u32) -> f64 {
+        self.expected(self.rating(home_id), self.rating(away_id))
+    }
+
+    fn expected(&self, home_rating: f64, away_rating: f64) -> f64 {
+        let difference = home_rating + self.options.home_advantage - away_rating;
+        1.0 / (1.0 + 10f64.powf(-difference / 400.0))
+    }
+
+    // Larger wins move ratings more, damped when the favorite wins so ratings don't inflate
+    fn multiplier(
+        &self,
+        home_rating: f64,
+        away_rating: f64,
+        home_score: u8,
+        away_score: u8,
+    ) -> f64 {
+        let margin = home_score.abs_diff(away_score);
+        if !self.options.margin_of_victory || margin == 0 {
+            return 1.0;
+        }
+        let home_difference = home_rating + self.options.home_advantage - away_rating;
+        let winner_difference = if home_score > away_score {
+            home_difference
+        } else {
+            -home_difference
+        };
+        (margin as f64 + 1.0).ln() * 2.2 / (winner_difference * 0.001 + 2.2)
+    }
+
+    // Moves every rating part of the way back to the initial rating
+    fn regress(&mut self) {
+        let (mean, share) = (self.options.initial_rating, self.options.season_regression);
+        for rating in self.ratings.values_mut() {
+            *rating += (mean - *rating) * share;
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn schedule() -> ScheduleResponse {
+        serde_json::from_str(include_str!("../tests/fixtures/schedule_seasons.json")).unwrap()
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-3
+    }
+
+    #[test]
+    fn replay_schedule() {
+        let mut elo = EloRatings::default();
+        elo.replay(&schedule());
+
+        // the 2023 World Series and two 2024 openers, the second CHC at TEX game isn't played yet
+        let history = elo.history();
+        assert_eq!(history.len(), 7);
+        assert_eq!(history[0].game_pk, 748548);
+        assert_eq!(history[0].date, NaiveDate::from_ymd_opt(2023, 10, 27));
+        assert_eq!(history[5].season, Some(2024));
+
+        // TEX won game 1 by a run at home as a slight favorite
+        assert!(close(history[0].home_win_probability, 0.5345));
+        assert!(close(history[0].home_rating_after, 1501.2768));
+        assert!(close(history[0].away_rating_after, 1498.7232));
+        // ARI's 9-1 win in game 2 moved the ratings more
+        assert!(close(history[1].away_rating_after, 1503.5107));
+
+        assert!(close(elo.rating(140), 1505.4135));
+        assert!(close(elo.rating(109), 1501.0266));
+        assert!(close(elo.rating(115), 1494.8179));
+        assert!(close(elo.rating(112), 1498.7419));
+        assert_eq!(elo.rating(147), 1500.0);
+        assert_eq!(elo.ratings()[0].0, 140);
+
+        let rangers = elo.team_history(140);
+        assert_eq!(rangers.len(), 6);
+        assert_eq!(
+            rangers.last().unwrap().0,
+            NaiveDate::from_ymd_opt(2024, 3, 28)
+        );
+
+        assert!(close(elo.win_probability(140, 109), 0.5408));
+        assert!(close(elo.win_probability(140, 140), 0.5345));
+    }
+
+    #[test]
+    fn season_regression() {
+        let mut elo = EloRatings::default();
+        elo.replay(&schedule());
+
+        // a third of the distance to 1500 is removed before the 2024 openers, TEX started first
+        let history = elo.history();
+        let rangers_2023 = history[4].away_rating_after;
+        assert!(close(rangers_2023, 1506.2331));
+        assert!(close(history[5].home_rating_before, 1504.1554));
+
+        let mut elo = EloRatings::new(EloOptions {
+            season_regression: 0.0,
+            ..Default::default()
+        });
+        elo.replay(&schedule());
+        assert!(close(elo.history()[5].home_rating_before, rangers_2023));
+    }
+
+    #[test]
+    fn rating_updates() {
+        let mut elo = EloRatings::default();
+        // E = 1 / (1 + 10^(-24 / 400)) = 0.53448 for even teams
+        // multiplier = ln(1 + 1) * 2.2 / (24 * 0.001 + 2.2) = 0.68567
+        // change = 4 * 0.68567 * (1 - 0.53448) = 1.27676
+        let game = elo
+            .add_game(GameResult {
+                game_pk: 1,
+                season: Some(2023),
+                home_id: 140,
+                away_id: 109,
+                home_score: 4,
+                away_score: 3,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(close(game.home_win_probability, 0.53448));
+        assert!(close(game.home_rating_after, 1501.27676));
+        assert!(close(game.away_rating_after, 1498.72324));
+
+        // a third of the way back to 1500, 1499.14883 and 1500.85117
+        // E = 1 / (1 + 10^(-(1499.14883 + 24 - 1500.85117) / 400)) = 0.53204
+        // multiplier = ln(3 + 1) * 2.2 / (-22.29766 * 0.001 + 2.2) = 1.40049
+        // change = 4 * 1.40049 * (0 - 0.53204) = -2.98049
+        let game = elo
+            .add_game(GameResult {
+                game_pk: 2,
+                season: Some(2024),
+                home_id: 109,
+                away_id: 140,
+                home_score: 0,
+                away_score: 3,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(close(game.home_rating_before, 1499.14883));
+        assert!(close(game.away_rating_before, 1500.85117));
+        assert!(close(game.home_win_probability, 0.53204));
+        assert!(close(game.home_rating_after, 1496.16834));
+        assert!(close(game.away_rating_after, 1503.83166));
+    }
+
+    #[test]
+    fn seasons_not_calendar_years() {
+        // a 2023 game finished in the next calendar year doesn't start the 2024 season
+        let mut elo = EloRatings::default();
+        elo.add_game(GameResult {
+            game_pk: 1,
+            season: Some(2023),
+            date: NaiveDate::from_ymd_opt(2023, 10, 1),
+            home_id: 140,
+            away_id: 109,
+            home_score: 4,
+            away_score: 3,
+        });
+        let rating = elo.rating(140);
+        let game = elo
+            .add_game(GameResult {
+                game_pk: 2,
+                season: Some(2023),
+                date: NaiveDate::from_ymd_opt(2024, 1, 2),
+                home_id: 140,
+                away_id: 109,
+                home_score: 3,
+                away_score: 3,
+            })
+            .unwrap();
+        assert_eq!(game.home_rating_before, rating);
+    }
+
+    #[test]
+    fn reproducible() {
+        let mut first = EloRatings::default();
+        first.replay(&schedule());
+        let mut second = EloRatings::default();
+        second.replay(&schedule());
+        assert_eq!(first.history(), second.history());
+
+        // games already counted are skipped
+        second.replay(&schedule());
+        assert_eq!(first.history(), second.history());
+        assert!(second
+            .add_game(GameResult {
+                game_pk: 748548,
+                season: Some(2023),
+                home_id: 140,
+                away_id: 109,
+                home_score: 6,
+                away_score: 5,
+                ..Default::default()
+            })
+            .is_none());
+    }
+
+    #[test]
+    fn margin_of_victory() {
+        let mut elo = EloRatings::new(EloOptions {
+            margin_of_victory: false,
+            home_advantage: 0.0,
+            ..Default::default()
+        });
+        let game = elo
+            .add_game(GameResult {
+                game_pk: 1,
+                home_id: 140,
+                away_id: 109,
+                home_score: 10,
+                away_score: 0,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(close(game.home_win_probability, 0.5));
+        assert!(close(game.home_rating_after, 1502.0));
+
+        // a blowout moves ratings more than a one run game
+        let mut elo = EloRatings::default();
+        let blowout = elo
+            .add_game(GameResult {
+                game_pk: 1,
+                home_id: 140,
+                away_id: 109,
+                home_score: 10,
+                away_score: 0,
+                ..Default::default()
+            })
+            .unwrap()
+            .clone();
+        let close_game = elo
+            .add_game(GameResult {
+                game_pk: 2,
+                home_id: 115,
+                away_id: 112,
+                home_score: 2,
+                away_score: 1,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(blowout.home_rating_after > close_game.home_rating_after);
+
+        // a tie costs the home team the edge it was expected to have
+        let tie = elo
+            .add_game(GameResult {
+                game_pk: 3,
+                home_id: 147,
+                away_id: 111,
+                home_score: 3,
+                away_score: 3,
+                ..Default::default()
+            })
+            .unwrap();
+        assert!(tie.home_rating_after < 1500.0);
+    }
+}
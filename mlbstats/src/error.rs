@@ -5,8 +5,8 @@ pub enum MLBStatsError {
     #[error("request error")]
     ReqwestError(#[from] reqwest::Error),
 
-    #[error("failed to convert MLBTeam")]
-    MLBTeamConversionError,
+    #[error("failed to convert team: {0} is not the id of a current MLB team, use TeamRegistry for other teams")]
+    MLBTeamConversionError(u32),
 
     #[error("failed to convert {0}: {1} is not a {0}")]
     MLBConversionError(String,String),
@@ -0,0 +1,409 @@
+use std::{cell::RefCell, fmt::Display};
+
+use serde::{
+    de::{self, DeserializeOwned, DeserializeSeed, Visitor},
+    Serialize,
+};
+
+/// Field selection sent as the fields param. The API only returns fields whose name is in the
+/// list, at any depth, so nested objects need their parent field listed as well.
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct Fields(Vec<String>);
+
+impl Fields {
+    /// Creates a selection from field names as they appear in the API (e.g. gamePk)
+    pub fn new(fields: &[&str]) -> Self {
+        fields
+            .iter()
+            .fold(Self::default(), |fields, field| fields.with(field))
+    }
+
+    /// Derives the selection from the fields a model deserializes, so only what the model needs
+    /// is downloaded. Returns an empty selection (every field is returned) when the model can't
+    /// be fully traced, e.g. models holding maps or capturing unknown fields.
+    pub fn of<T: DeserializeOwned>() -> Self {
+        let trace = Trace::default();
+        if T::deserialize(Tracer {
+            trace: &trace,
+            depth: 0,
+        })
+        .is_err()
+            || trace.untraceable.take()
+        {
+            return Self::default();
+        }
+        trace.fields.take()
+    }
+
+    /// Adds a field to the selection
+    pub fn with(mut self, field: &str) -> Self {
+        if !self.0.iter().any(|f| f == field) {
+            self.0.push(field.into());
+        }
+        self
+    }
+
+    /// Combines two selections
+    pub fn and(self, other: Fields) -> Self {
+        other
+            .0
+            .iter()
+            .fold(self, |fields, field| fields.with(field))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn contains(&self, field: &str) -> bool {
+        self.0.iter().any(|f| f == field)
+    }
+}
+
+impl Display for Fields {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.join(","))
+    }
+}
+
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+// Models nest a handful of levels deep, anything deeper is a recursive type
+const MAX_DEPTH: usize = 16;
+
+#[derive(Default)]
+struct Trace {
+    fields: RefCell<Fields>,
+    untraceable: RefCell<bool>,
+}
+
+#[derive(Debug)]
+struct TraceError(String);
+
+impl Display for TraceError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for TraceError {}
+
+impl de::Error for TraceError {
+    fn custom<T: Display>(msg: T) -> Self {
+        TraceError(msg.to_string())
+    }
+}
+
+// Deserializer which records the field names of every struct it is asked to deserialize and
+// feeds placeholder values to everything else
+#[derive(Clone, Copy)]
+struct Tracer<'a> {
+    trace: &'a Trace,
+    depth: usize,
+}
+
+impl<'a> Tracer<'a> {
+    fn nested(self) -> Self {
+        Tracer {
+            trace: self.trace,
+            depth: self.depth + 1,
+        }
+    }
+
+    fn untraceable<'de, V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        *self.trace.untraceable.borrow_mut() = true;
+        visitor.visit_unit()
+    }
+}
+
+macro_rules! trace_value {
+    ($($method:ident => $visit:ident($value:expr)),* $(,)?) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+                visitor.$visit($value)
+            }
+        )*
+    };
+}
+
+impl<'de, 'a> de::Deserializer<'de> for Tracer<'a> {
+    type Error = TraceError;
+
+    trace_value! {
+        deserialize_bool => visit_bool(false),
+        deserialize_i8 => visit_i64(0),
+        deserialize_i16 => visit_i64(0),
+        deserialize_i32 => visit_i64(0),
+        deserialize_i64 => visit_i64(0),
+        deserialize_u8 => visit_u64(0),
+        deserialize_u16 => visit_u64(0),
+        deserialize_u32 => visit_u64(0),
+        deserialize_u64 => visit_u64(0),
+        deserialize_f32 => visit_f64(0.0),
+        deserialize_f64 => visit_f64(0.0),
+        deserialize_char => visit_char(' '),
+        deserialize_str => visit_str(""),
+        deserialize_string => visit_str(""),
+        deserialize_bytes => visit_bytes(&[]),
+        deserialize_byte_buf => visit_bytes(&[]),
+        deserialize_identifier => visit_str(""),
+    }
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        self.untraceable(visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        visitor.visit_seq(TracerSeq {
+            tracer: self.nested(),
+            remaining: 1,
+        })
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_seq(TracerSeq {
+            tracer: self.nested(),
+            remaining: len,
+        })
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        self.deserialize_tuple(len, visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TraceError> {
+        // maps and flattened structs don't expose their keys
+        *self.trace.untraceable.borrow_mut() = true;
+        visitor.visit_map(TracerMap {
+            tracer: self.nested(),
+            fields: [].iter(),
+        })
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        if self.depth > MAX_DEPTH {
+            return self.untraceable(visitor);
+        }
+        for field in fields {
+            let traced = self.trace.fields.take().with(field);
+            self.trace.fields.replace(traced);
+        }
+        visitor.visit_map(TracerMap {
+            tracer: self.nested(),
+            fields: fields.iter(),
+        })
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        visitor.visit_enum(TracerEnum {
+            tracer: self.nested(),
+            variant: variants.first().copied().unwrap_or_default(),
+        })
+    }
+}
+
+struct TracerSeq<'a> {
+    tracer: Tracer<'a>,
+    remaining: usize,
+}
+
+impl<'de, 'a> de::SeqAccess<'de> for TracerSeq<'a> {
+    type Error = TraceError;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, TraceError> {
+        if self.remaining == 0 {
+            return Ok(None);
+        }
+        self.remaining -= 1;
+        seed.deserialize(self.tracer).map(Some)
+    }
+}
+
+struct TracerMap<'a> {
+    tracer: Tracer<'a>,
+    fields: std::slice::Iter<'static, &'static str>,
+}
+
+impl<'de, 'a> de::MapAccess<'de> for TracerMap<'a> {
+    type Error = TraceError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, TraceError> {
+        match self.fields.next() {
+            Some(field) => seed
+                .deserialize(de::value::BorrowedStrDeserializer::new(field))
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, TraceError> {
+        seed.deserialize(self.tracer)
+    }
+}
+
+struct TracerEnum<'a> {
+    tracer: Tracer<'a>,
+    variant: &'static str,
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for TracerEnum<'a> {
+    type Error = TraceError;
+    type Variant = Self;
+
+    fn variant_seed<V: DeserializeSeed<'de>>(
+        self,
+        seed: V,
+    ) -> Result<(V::Value, Self), TraceError> {
+        let variant = seed.deserialize(de::value::BorrowedStrDeserializer::new(self.variant))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de, 'a> de::VariantAccess<'de> for TracerEnum<'a> {
+    type Error = TraceError;
+
+    fn unit_variant(self) -> Result<(), TraceError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<T: DeserializeSeed<'de>>(
+        self,
+        seed: T,
+    ) -> Result<T::Value, TraceError> {
+        seed.deserialize(self.tracer)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(
+        self,
+        len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        de::Deserializer::deserialize_tuple(self.tracer, len, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TraceError> {
+        de::Deserializer::deserialize_struct(self.tracer, "", fields, visitor)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::{games::LiveFeedResponse, schedule::ScheduleResponse};
+    use serde::Deserialize;
+
+    #[test]
+    fn fields_serialization() {
+        let fields = Fields::new(&["dates", "games", "gamePk"]).with("games");
+        assert_eq!(fields.to_string(), "dates,games,gamePk");
+        assert_eq!(
+            fields.and(Fields::new(&["date"])).to_string(),
+            "dates,games,gamePk,date"
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "unknown_fields"))]
+    fn fields_of_model() {
+        let fields = Fields::of::<LiveFeedResponse>();
+        assert_eq!(
+            fields.to_string(),
+            "gamePk,liveData,boxscore,officials,official,officialType,id,fullName,link,firstName,\
+             lastName,primaryNumber,birthDate,height,weight,active,captain,mlbDebutDate,batSide,\
+             pitchHand,primaryPosition,code,description,name,type,abbreviation"
+        );
+
+        let fields = Fields::of::<ScheduleResponse>();
+        assert!(fields.contains("dates"));
+        assert!(fields.contains("gamePk"));
+        assert!(fields.contains("currentInning"));
+        assert!(fields.contains("gameType"));
+    }
+
+    #[test]
+    fn untraceable_models() {
+        #[derive(Deserialize)]
+        #[allow(dead_code)]
+        struct WithMap {
+            id: u32,
+            extra: HashMap<String, String>,
+        }
+        assert!(Fields::of::<WithMap>().is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "unknown_fields")]
+    fn unknown_fields_disable_tracing() {
+        assert!(Fields::of::<LiveFeedResponse>().is_empty());
+        assert!(Fields::of::<ScheduleResponse>().is_empty());
+    }
+}
@@ -0,0 +1,281 @@
+use std::collections::BTreeMap;
+
+use crate::{standings::StandingsResponse, stats::player_stats::StatSplit, teams::Team};
+
+/// One identity of a franchise over a span of seasons (e.g. the Montreal Expos 1969-2004)
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct FranchiseEra {
+    pub team_id: u32,
+    pub name: String,
+    /// Location the club played in (e.g. Montreal)
+    pub franchise_name: String,
+    pub club_name: String,
+    pub abbreviation: String,
+    pub first_season: u32,
+    /// Last season of the era, None for the current era
+    pub last_season: Option<u32>,
+}
+
+impl FranchiseEra {
+    pub fn contains(&self, season: u32) -> bool {
+        season >= self.first_season && self.last_season.is_none_or(|last| season <= last)
+    }
+}
+
+/// A club followed across relocations and renames, identified by the id of its current team
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct Franchise {
+    pub id: u32,
+    pub name: String,
+    /// Eras ordered from oldest to most recent
+    pub eras: Vec<FranchiseEra>,
+}
+
+impl Franchise {
+    /// Builds a franchise from the response of v1/teams/{teamId}/history. Each entry describes an
+    /// identity as of its final season, consecutive seasons under the same name are merged into
+    /// one era. Returns None for an empty history.
+    pub fn from_history(mut history: Vec<Team>) -> Option<Self> {
+        history.sort_by_key(|team| team.season);
+        let current = history.last()?.clone();
+        let first_season = history
+            .iter()
+            .filter_map(|team| team.first_year_of_play.parse::<u32>().ok())
+            .min()
+            .unwrap_or(history[0].season);
+
+        let mut eras: Vec<FranchiseEra> = vec![];
+        for team in &history {
+            match eras.last_mut() {
+                Some(era) if era.team_id == team.id && era.name == team.name => {
+                    era.last_season = Some(team.season);
+                }
+                _ => eras.push(FranchiseEra {
+                    team_id: team.id,
+                    name: team.name.clone(),
+                    franchise_name: team.franchise_name.clone(),
+                    club_name: team.club_name.clone(),
+                    abbreviation: team.abbreviation.clone(),
+                    first_season: eras
+                        .last()
+                        .and_then(|era| era.last_season)
+                        .map_or(first_season, |last| last + 1),
+                    last_season: Some(team.season),
+                }),
+            }
+        }
+        if current.active {
+            if let Some(era) = eras.last_mut() {
+                era.last_season = None;
+            }
+        }
+
+        Some(Franchise {
+            id: current.id,
+            name: current.name,
+            eras,
+        })
+    }
+
+    /// Era the franchise was in during a season
+    pub fn era(&self, season: u32) -> Option<&FranchiseEra> {
+        self.eras.iter().find(|era| era.contains(season))
+    }
+
+    /// Every team id the franchise has played under
+    pub fn team_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.eras.iter().map(|era| era.team_id).collect();
+        ids.dedup();
+        ids
+    }
+}
+
+/// Regular season totals of a franchise across the seasons it was aggregated over
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct FranchiseRecord {
+    pub franchise_id: u32,
+    pub seasons: u32,
+    pub wins: u32,
+    pub losses: u32,
+    pub runs_scored: u32,
+    pub runs_allowed: u32,
+}
+
+impl FranchiseRecord {
+    pub fn winning_percentage(&self) -> f64 {
+        match self.wins + self.losses {
+            0 => 0.0,
+            games => self.wins as f64 / games as f64,
+        }
+    }
+
+    pub fn run_differential(&self) -> i64 {
+        self.runs_scored as i64 - self.runs_allowed as i64
+    }
+}
+
+/// Maps historical team ids and seasons to the franchise they belong to
+#[derive(Default, Debug, Clone)]
+pub struct FranchiseMap {
+    franchises: Vec<Franchise>,
+}
+
+impl FranchiseMap {
+    pub fn new(franchises: Vec<Franchise>) -> Self {
+        FranchiseMap { franchises }
+    }
+
+    pub fn franchises(&self) -> &[Franchise] {
+        &self.franchises
+    }
+
+    pub fn get(&self, franchise_id: u32) -> Option<&Franchise> {
+        self.franchises.iter().find(|f| f.id == franchise_id)
+    }
+
+    /// Franchise a team belonged to in a season. Falls back to any franchise the team id has
+    /// played under when the season is outside of the known eras.
+    pub fn franchise_of(&self, team_id: u32, season: u32) -> Option<&Franchise> {
+        self.franchises
+            .iter()
+            .find(|f| {
+                f.eras
+                    .iter()
+                    .any(|era| era.team_id == team_id && era.contains(season))
+            })
+            .or_else(|| {
+                self.franchises
+                    .iter()
+                    .find(|f| f.eras.iter().any(|era| era.team_id == team_id))
+            })
+    }
+
+    pub fn franchise_id(&self, team_id: u32, season: u32) -> Option<u32> {
+        self.franchise_of(team_id, season).map(|f| f.id)
+    }
+
+    /// Totals the standings of each season per franchise, teams without a known franchise are
+    /// skipped. Standings records don't include their season so it's provided with each response.
+    pub fn standings<'a>(
+        &self,
+        seasons: impl IntoIterator<Item = (u32, &'a StandingsResponse)>,
+    ) -> Vec<FranchiseRecord> {
+        let mut records: BTreeMap<u32, FranchiseRecord> = BTreeMap::new();
+        for (season, standings) in seasons {
+            for team in standings.records.iter().flat_map(|r| r.team_records.iter()) {
+                let Some(franchise_id) = self.franchise_id(team.team.id, season) else {
+                    continue;
+                };
+                let record = records.entry(franchise_id).or_insert(FranchiseRecord {
+                    franchise_id,
+                    ..Default::default()
+                });
+                record.seasons += 1;
+                record.wins += team.wins;
+                record.losses += team.losses;
+                record.runs_scored += team.runs_scored;
+                record.runs_allowed += team.runs_allowed;
+            }
+        }
+        records.into_values().collect()
+    }
+
+    /// Groups stat rows by the franchise of the team they were accumulated with, rows without a
+    /// season or a known franchise are skipped
+    pub fn group_splits<'a, S>(
+        &self,
+        splits: impl IntoIterator<Item = &'a StatSplit<S>>,
+    ) -> BTreeMap<u32, Vec<&'a StatSplit<S>>>
+    where
+        S: 'a,
+    {
+        let mut groups: BTreeMap<u32, Vec<&'a StatSplit<S>>> = BTreeMap::new();
+        for split in splits {
+            let franchise_id = split
+                .season
+                .parse::<u32>()
+                .ok()
+                .and_then(|season| self.franchise_id(split.team.id, season));
+            if let Some(franchise_id) = franchise_id {
+                groups.entry(franchise_id).or_default().push(split);
+            }
+        }
+        groups
+    }
+}
+
+impl FromIterator<Franchise> for FranchiseMap {
+    fn from_iter<T: IntoIterator<Item = Franchise>>(iter: T) -> Self {
+        FranchiseMap::new(iter.into_iter().collect())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{
+        stats::player_stats::{HittingStats, StatsResponse},
+        teams::TeamsResponse,
+    };
+
+    fn franchise(fixture: &str) -> Franchise {
+        let history: TeamsResponse = serde_json::from_str(fixture).unwrap();
+        Franchise::from_history(history.teams).unwrap()
+    }
+
+    fn franchises() -> FranchiseMap {
+        [
+            franchise(include_str!("../tests/fixtures/team_history_120.json")),
+            franchise(include_str!("../tests/fixtures/team_history_140.json")),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn franchise_eras() {
+        let nationals = franchise(include_str!("../tests/fixtures/team_history_120.json"));
+        assert_eq!(nationals.id, 120);
+        assert_eq!(nationals.name, "Washington Nationals");
+        assert_eq!(nationals.eras.len(), 2);
+        assert_eq!(nationals.eras[0].name, "Montreal Expos");
+        assert_eq!(nationals.eras[0].first_season, 1969);
+        assert_eq!(nationals.eras[0].last_season, Some(2004));
+        assert_eq!(nationals.eras[1].first_season, 2005);
+        assert_eq!(nationals.eras[1].last_season, None);
+        assert_eq!(nationals.era(1994).unwrap().abbreviation, "MON");
+        assert_eq!(nationals.era(2019).unwrap().abbreviation, "WSH");
+        assert!(nationals.era(1960).is_none());
+        assert_eq!(nationals.team_ids(), vec![120]);
+    }
+
+    #[test]
+    fn franchise_lookup() {
+        let map = franchises();
+        let rangers = map.franchise_of(140, 1965).unwrap();
+        assert_eq!(rangers.name, "Texas Rangers");
+        assert_eq!(rangers.era(1965).unwrap().name, "Washington Senators");
+        assert_eq!(map.franchise_id(120, 2004), Some(120));
+        assert_eq!(map.franchise_id(120, 1900), Some(120));
+        assert_eq!(map.franchise_id(147, 2023), None);
+    }
+
+    #[test]
+    fn franchise_aggregation() {
+        let map = franchises();
+        let standings: StandingsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap();
+        let records = map.standings([(2023, &standings), (2023, &standings)]);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].franchise_id, 140);
+        assert_eq!(records[0].seasons, 2);
+        assert_eq!((records[0].wins, records[0].losses), (180, 144));
+        assert!((records[0].winning_percentage() - 0.5556).abs() < 0.001);
+
+        let stats: StatsResponse<HittingStats> =
+            serde_json::from_str(include_str!("../tests/fixtures/stats_hitting.json")).unwrap();
+        let groups = map.group_splits(stats.splits());
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[&140][0].player.full_name, "Corey Seager");
+    }
+}
@@ -1,8 +1,18 @@
+use std::fmt::Display;
+
+use chrono::{DateTime, FixedOffset, NaiveDate, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 
-use crate::{linescore::Linescore, players::Player, plays::Play, teams::Team, types::Record};
+use crate::{
+    linescore::Linescore,
+    players::Player,
+    plays::Play,
+    teams::Team,
+    types::{optional_date, optional_datetime, Record},
+    venue::Venue,
+};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Game {
     /// Game ID
@@ -11,8 +21,31 @@ pub struct Game {
     pub link: String,
     /// Type of game being played (e.g. Regular Season)
     pub game_type: GameTypes,
+    /// Season the game counts towards (e.g. 2023)
+    pub season: String,
+    /// Scheduled start time of the game, the time is a placeholder when the start time is TBD
+    #[serde(with = "optional_datetime")]
+    pub game_date: Option<DateTime<Utc>>,
+    /// Date the game counts towards, differs from the game date for games starting after midnight
+    /// UTC or resumed on a later day
+    #[serde(with = "optional_date")]
+    pub official_date: Option<NaiveDate>,
+    /// Whether the game is played during the day or at night (e.g. day)
+    pub day_night: String,
+    /// Name of the series the game belongs to (e.g. Division Series)
+    pub series_description: String,
+    /// Game number within the series
+    pub series_game_number: u32,
+    /// Number of games scheduled in the series, for postseason rounds this is the maximum
+    pub games_in_series: u32,
+    /// Whether the game is part of a double header
+    pub double_header: DoubleHeader,
+    /// Game of the day for the teams, 2 for the second game of a double header
+    pub game_number: u32,
     /// Teams playing in the game
     pub teams: GameTeams,
+    /// Venue the game is played at, the time zone requires the venue(timezone) hydration
+    pub venue: Venue,
     /// Contains various information about the game's status
     pub status: GameStatus,
     /// Information about every inning of the game
@@ -25,9 +58,39 @@ pub struct Game {
     pub home_runs: Vec<Play>,
     /// List of players that played in the game
     pub lineups: Lineup,
+    /// Umpires working the game, requires the officials hydration on the schedule
+    pub officials: Vec<Official>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
 impl Game {
+    /// Returns the home plate umpire when officials are present
+    pub fn home_plate_umpire(&self) -> Option<&Player> {
+        self.officials
+            .iter()
+            .find(|official| official.official_type == "Home Plate")
+            .map(|official| &official.official)
+    }
+
+    /// Start time in the time zone of the venue. Returns None when the start time is TBD or the
+    /// venue time zone wasn't hydrated.
+    pub fn local_start_time(&self) -> Option<DateTime<FixedOffset>> {
+        let offset = self.venue.time_zone.as_ref()?.offset_at_game_time;
+        self.start_time_in(&FixedOffset::east_opt(offset * 3600)?)
+    }
+
+    /// Start time in the provided time zone (e.g. chrono::Local), returns None when the start time
+    /// is TBD
+    pub fn start_time_in<Tz: TimeZone>(&self, tz: &Tz) -> Option<DateTime<Tz>> {
+        if self.status.start_time_tbd {
+            return None;
+        }
+        Some(self.game_date?.with_timezone(tz))
+    }
+
     pub fn score(&self) -> String {
         format!(
             "{}\n{}: {}\n{}: {}",
@@ -40,7 +103,7 @@ impl Game {
     }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct GameStatus {
     pub abstract_game_state: String,
@@ -50,24 +113,221 @@ pub struct GameStatus {
     #[serde(rename = "startTimeTBD")]
     pub start_time_tbd: bool,
     pub abstract_game_code: String,
+    /// Reason for a delay or postponement (e.g. Rain), when provided
+    pub reason: Option<String>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl GameStatus {
+    /// Typed state of the game parsed from the detailed state
+    pub fn state(&self) -> GameState {
+        match GameState::from(self.detailed_state.as_str()) {
+            GameState::Delayed(None) => GameState::Delayed(self.reason.clone()),
+            GameState::DelayedStart(None) => GameState::DelayedStart(self.reason.clone()),
+            GameState::Suspended(None) => GameState::Suspended(self.reason.clone()),
+            GameState::Postponed(None) => GameState::Postponed(self.reason.clone()),
+            GameState::Cancelled(None) => GameState::Cancelled(self.reason.clone()),
+            GameState::CompletedEarly(None) => GameState::CompletedEarly(self.reason.clone()),
+            GameState::Other(state, None) => GameState::Other(state, self.abstract_state()),
+            state => state,
+        }
+    }
+
+    /// Coarse state of the game, None when the abstract state wasn't returned
+    pub fn abstract_state(&self) -> Option<AbstractGameState> {
+        match self.abstract_game_state.as_str() {
+            "" => None,
+            state => Some(AbstractGameState::from(state)),
+        }
+    }
+}
+
+/// Coarse state of a game sent as the abstract game state, every detailed state belongs to one
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum AbstractGameState {
+    Preview,
+    Live,
+    Final,
+    /// Abstract state not modeled by the crate
+    Other(String),
+}
+
+impl From<&str> for AbstractGameState {
+    fn from(value: &str) -> Self {
+        match value {
+            "Preview" => AbstractGameState::Preview,
+            "Live" => AbstractGameState::Live,
+            "Final" => AbstractGameState::Final,
+            _ => AbstractGameState::Other(value.to_string()),
+        }
+    }
+}
+
+impl Display for AbstractGameState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AbstractGameState::Preview => write!(f, "Preview"),
+            AbstractGameState::Live => write!(f, "Live"),
+            AbstractGameState::Final => write!(f, "Final"),
+            AbstractGameState::Other(state) => write!(f, "{}", state),
+        }
+    }
+}
+
+/// State of a game, states with a reason hold the reason when one is provided (e.g. Rain)
+#[derive(PartialEq, Debug, Clone)]
+pub enum GameState {
+    /// Scheduled and not yet started
+    Preview,
+    PreGame,
+    Warmup,
+    InProgress,
+    ManagerChallenge,
+    /// Delayed after the game has started
+    Delayed(Option<String>),
+    /// Delayed before the first pitch
+    DelayedStart(Option<String>),
+    Suspended(Option<String>),
+    Postponed(Option<String>),
+    Cancelled(Option<String>),
+    Final,
+    CompletedEarly(Option<String>),
+    /// Game ended but is not yet final
+    GameOver,
+    /// Detailed state not modeled by the crate with the abstract state of the game, when known.
+    /// The predicates fall back to the abstract state.
+    Other(String, Option<AbstractGameState>),
+}
+
+impl GameState {
+    /// Whether the game has started and not yet ended, including delays and challenges
+    pub fn is_live(&self) -> bool {
+        match self {
+            GameState::Other(_, state) => state == &Some(AbstractGameState::Live),
+            _ => matches!(
+                self,
+                GameState::Warmup
+                    | GameState::InProgress
+                    | GameState::ManagerChallenge
+                    | GameState::Delayed(_)
+            ),
+        }
+    }
+
+    /// Whether the game was played to completion, postponed and cancelled games are not final
+    pub fn is_final(&self) -> bool {
+        match self {
+            GameState::Other(_, state) => state == &Some(AbstractGameState::Final),
+            _ => matches!(
+                self,
+                GameState::Final | GameState::CompletedEarly(_) | GameState::GameOver
+            ),
+        }
+    }
+
+    pub fn is_delayed(&self) -> bool {
+        matches!(self, GameState::Delayed(_) | GameState::DelayedStart(_))
+    }
+
+    /// Reason for the state, when provided
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            GameState::Delayed(reason)
+            | GameState::DelayedStart(reason)
+            | GameState::Suspended(reason)
+            | GameState::Postponed(reason)
+            | GameState::Cancelled(reason)
+            | GameState::CompletedEarly(reason) => reason.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+// Detailed states are formatted as "State: Reason" (e.g. Delayed Start: Rain). The abstract state
+// of an unknown detailed state isn't known from the detailed state alone, see GameStatus::state.
+impl From<&str> for GameState {
+    fn from(value: &str) -> Self {
+        let (state, reason) = match value.split_once(':') {
+            Some((state, reason)) => (state.trim(), Some(reason.trim().to_string())),
+            None => (value.trim(), None),
+        };
+        match state {
+            "Scheduled" | "Preview" => GameState::Preview,
+            "Pre-Game" => GameState::PreGame,
+            "Warmup" => GameState::Warmup,
+            "In Progress" => GameState::InProgress,
+            "Manager challenge" | "Manager Challenge" => GameState::ManagerChallenge,
+            "Delayed" => GameState::Delayed(reason),
+            "Delayed Start" => GameState::DelayedStart(reason),
+            "Suspended" => GameState::Suspended(reason),
+            "Postponed" => GameState::Postponed(reason),
+            "Cancelled" => GameState::Cancelled(reason),
+            "Final" => GameState::Final,
+            "Completed Early" => GameState::CompletedEarly(reason),
+            "Game Over" => GameState::GameOver,
+            _ => GameState::Other(value.to_string(), None),
+        }
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+impl Display for GameState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let state = match self {
+            GameState::Preview => "Scheduled",
+            GameState::PreGame => "Pre-Game",
+            GameState::Warmup => "Warmup",
+            GameState::InProgress => "In Progress",
+            GameState::ManagerChallenge => "Manager challenge",
+            GameState::Delayed(_) => "Delayed",
+            GameState::DelayedStart(_) => "Delayed Start",
+            GameState::Suspended(_) => "Suspended",
+            GameState::Postponed(_) => "Postponed",
+            GameState::Cancelled(_) => "Cancelled",
+            GameState::Final => "Final",
+            GameState::CompletedEarly(_) => "Completed Early",
+            GameState::GameOver => "Game Over",
+            GameState::Other(state, _) => state,
+        };
+        match self.reason() {
+            Some(reason) => write!(f, "{}: {}", state, reason),
+            None => write!(f, "{}", state),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct GameTeams {
     pub away: GameTeam,
     pub home: GameTeam,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct GameTeam {
     pub score: Option<u8>,
     pub team: Team,
+    /// Season record of the team, during the postseason this is the record of the series
     pub league_record: Record,
+    /// Whether the team won the game, only present once the game is final
+    pub is_winner: Option<bool>,
+    pub series_number: Option<u32>,
+    /// Postseason seed of the team, when provided
+    pub seed: Option<u32>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 pub enum GameTypes {
     /// Spring Training
     S,
@@ -95,15 +355,195 @@ pub enum GameTypes {
     E,
 }
  
+#[allow(clippy::derivable_impls)]
 impl Default for GameTypes {
     fn default() -> Self {
         Self::R
     }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+/// Double header status of a game
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone)]
+pub enum DoubleHeader {
+    /// Single game
+    #[default]
+    #[serde(rename = "N")]
+    No,
+    /// Both games played with a single admission
+    #[serde(rename = "Y")]
+    Traditional,
+    /// Separate admission for each game
+    #[serde(rename = "S")]
+    Split,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Lineup {
     home_players: Vec<Player>,
     away_players: Vec<Player>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Official {
+    /// Umpire working the game, the id matches the person returned from the umpires job endpoint
+    pub official: Player,
+    /// Position of the umpire (e.g. Home Plate, First Base)
+    pub official_type: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Subset of the live game feed, only the parts modeled by the crate are deserialized
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LiveFeedResponse {
+    pub game_pk: u32,
+    pub live_data: LiveData,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LiveData {
+    pub boxscore: Boxscore,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Boxscore {
+    pub officials: Vec<Official>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::schedule::ScheduleResponse;
+
+    #[test]
+    fn game_states() {
+        assert_eq!(GameState::from("In Progress"), GameState::InProgress);
+        assert_eq!(
+            GameState::from("Delayed Start: Rain"),
+            GameState::DelayedStart(Some("Rain".into()))
+        );
+        assert_eq!(GameState::from("Final: Tied"), GameState::Final);
+        assert_eq!(
+            GameState::from("Umpire review"),
+            GameState::Other("Umpire review".into(), None)
+        );
+
+        assert!(GameState::Delayed(None).is_live());
+        assert!(GameState::Delayed(None).is_delayed());
+        assert!(!GameState::DelayedStart(None).is_live());
+        assert!(GameState::CompletedEarly(None).is_final());
+        assert!(!GameState::Postponed(None).is_final());
+    }
+
+    #[test]
+    fn unknown_states_use_abstract_state() {
+        let status = |abstract_game_state: &str, detailed_state: &str| GameStatus {
+            abstract_game_state: abstract_game_state.into(),
+            detailed_state: detailed_state.into(),
+            ..Default::default()
+        };
+
+        let review = status("Live", "Umpire review").state();
+        assert_eq!(
+            review,
+            GameState::Other("Umpire review".into(), Some(AbstractGameState::Live))
+        );
+        assert!(review.is_live());
+        assert!(!review.is_final());
+        assert_eq!(review.to_string(), "Umpire review");
+
+        let forfeit = status("Final", "Forfeit").state();
+        assert!(forfeit.is_final());
+        assert!(!forfeit.is_live());
+        assert!(!status("", "Forfeit").state().is_final());
+        assert_eq!(
+            status("Pending", "Forfeit").abstract_state(),
+            Some(AbstractGameState::Other("Pending".into()))
+        );
+    }
+
+    #[test]
+    fn game_state_round_trip() {
+        for state in [
+            "Scheduled",
+            "Warmup",
+            "Delayed: Rain",
+            "Completed Early: Cold",
+            "Game Over",
+            "Umpire review",
+        ] {
+            assert_eq!(GameState::from(state).to_string(), state);
+        }
+    }
+
+    #[test]
+    fn status_reason() {
+        let status = GameStatus {
+            detailed_state: "Postponed".into(),
+            reason: Some("Rain".into()),
+            ..Default::default()
+        };
+        assert_eq!(status.state(), GameState::Postponed(Some("Rain".into())));
+        assert_eq!(status.state().reason(), Some("Rain"));
+    }
+
+    #[test]
+    fn start_times() {
+        let schedule: ScheduleResponse = serde_json::from_str(include_str!(
+            "../tests/fixtures/schedule_double_header.json"
+        ))
+        .unwrap();
+        let games = schedule.games();
+        assert_eq!(
+            schedule.dates[0].date,
+            NaiveDate::from_ymd_opt(2023, 9, 23).unwrap()
+        );
+
+        // second game of the double header starts after midnight UTC
+        let game = &games[1];
+        assert_eq!(game.official_date, NaiveDate::from_ymd_opt(2023, 9, 23));
+        assert_eq!(
+            game.game_date,
+            Utc.with_ymd_and_hms(2023, 9, 24, 0, 5, 0).single()
+        );
+        assert_eq!(game.day_night, "night");
+        assert_eq!(game.venue.name, "Globe Life Field");
+        assert_eq!(
+            game.local_start_time().unwrap().to_rfc3339(),
+            "2023-09-23T19:05:00-05:00"
+        );
+
+        let eastern = FixedOffset::west_opt(4 * 3600).unwrap();
+        assert_eq!(
+            game.start_time_in(&eastern).unwrap().to_rfc3339(),
+            "2023-09-23T20:05:00-04:00"
+        );
+
+        let mut tbd = game.clone();
+        tbd.status.start_time_tbd = true;
+        assert!(tbd.start_time_in(&Utc).is_none());
+    }
 }
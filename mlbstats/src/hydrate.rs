@@ -0,0 +1,308 @@
+use std::{fmt::Display, marker::PhantomData};
+
+use serde::Serialize;
+
+use crate::stats::types::{StatGroup, StatType};
+
+/// Single hydration added to a response. Hydrations holding a Vec are serialized with the nested
+/// hydrations in parentheses (e.g. venue(location,timezone)).
+#[derive(PartialEq, Debug, Clone)]
+pub(crate) enum Hydrate {
+    Team,
+    Linescore,
+    ProbablePitcher,
+    Decisions,
+    Person,
+    Stats {
+        group: Vec<StatGroup>,
+        types: Vec<StatType>,
+    },
+    Broadcasts,
+    Weather,
+    Venue(Vec<Hydrate>),
+    Location,
+    Timezone,
+    Game(Vec<Hydrate>),
+    Content,
+    SeriesStatus,
+    Flags,
+    Officials,
+    League,
+    Division,
+    Sport,
+    Conference,
+}
+
+impl Display for Hydrate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Hydrate::Team => write!(f, "team"),
+            Hydrate::Linescore => write!(f, "linescore"),
+            Hydrate::ProbablePitcher => write!(f, "probablePitcher"),
+            Hydrate::Decisions => write!(f, "decisions"),
+            Hydrate::Person => write!(f, "person"),
+            Hydrate::Stats { group, types } => write!(
+                f,
+                "stats(group=[{}],type=[{}])",
+                join(group.iter().map(serde_name)),
+                join(types.iter().map(serde_name)),
+            ),
+            Hydrate::Broadcasts => write!(f, "broadcasts"),
+            Hydrate::Weather => write!(f, "weather"),
+            Hydrate::Venue(nested) => write_nested(f, "venue", nested),
+            Hydrate::Location => write!(f, "location"),
+            Hydrate::Timezone => write!(f, "timezone"),
+            Hydrate::Game(nested) => write_nested(f, "game", nested),
+            Hydrate::Content => write!(f, "content"),
+            Hydrate::SeriesStatus => write!(f, "seriesStatus"),
+            Hydrate::Flags => write!(f, "flags"),
+            Hydrate::Officials => write!(f, "officials"),
+            Hydrate::League => write!(f, "league"),
+            Hydrate::Division => write!(f, "division"),
+            Hydrate::Sport => write!(f, "sport"),
+            Hydrate::Conference => write!(f, "conference"),
+        }
+    }
+}
+
+/// Marker for hydrations supported by the schedule endpoint
+#[derive(Debug, Clone)]
+pub struct ScheduleEndpoint;
+
+/// Marker for hydrations supported by the standings endpoint
+#[derive(Debug, Clone)]
+pub struct StandingsEndpoint;
+
+/// Marker for hydrations supported by the roster endpoint
+#[derive(Debug, Clone)]
+pub struct RosterEndpoint;
+
+/// Marker for hydrations nested in the venue hydration of the schedule
+#[derive(Debug, Clone)]
+pub struct VenueHydration;
+
+/// Marker for hydrations nested in the game hydration of the schedule
+#[derive(Debug, Clone)]
+pub struct GameHydration;
+
+pub type ScheduleHydrations = Hydrations<ScheduleEndpoint>;
+pub type StandingsHydrations = Hydrations<StandingsEndpoint>;
+pub type RosterHydrations = Hydrations<RosterEndpoint>;
+pub type VenueHydrations = Hydrations<VenueHydration>;
+pub type GameHydrations = Hydrations<GameHydration>;
+
+/// Builder for the hydrate param. Only the hydrations supported by the endpoint `E` are available
+/// as builder methods, so passing a hydration to the wrong endpoint fails to compile.
+///
+/// `ScheduleHydrations::new().team().linescore()` serializes to `team,linescore`
+#[derive(Debug, Clone)]
+pub struct Hydrations<E> {
+    hydrations: Vec<Hydrate>,
+    endpoint: PhantomData<E>,
+}
+
+impl<E> Hydrations<E> {
+    pub fn new() -> Self {
+        Self {
+            hydrations: vec![],
+            endpoint: PhantomData,
+        }
+    }
+
+    /// Adds a hydration without checking that the endpoint supports it
+    pub(crate) fn with(mut self, hydrate: Hydrate) -> Self {
+        if !self.hydrations.contains(&hydrate) {
+            self.hydrations.push(hydrate);
+        }
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hydrations.is_empty()
+    }
+}
+
+impl<E> Default for Hydrations<E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Display for Hydrations<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", join(self.hydrations.iter()))
+    }
+}
+
+impl Hydrations<ScheduleEndpoint> {
+    pub fn team(self) -> Self {
+        self.with(Hydrate::Team)
+    }
+
+    pub fn linescore(self) -> Self {
+        self.with(Hydrate::Linescore)
+    }
+
+    pub fn probable_pitcher(self) -> Self {
+        self.with(Hydrate::ProbablePitcher)
+    }
+
+    /// Winning, losing and save pitchers of final games
+    pub fn decisions(self) -> Self {
+        self.with(Hydrate::Decisions)
+    }
+
+    pub fn person(self) -> Self {
+        self.with(Hydrate::Person)
+    }
+
+    pub fn stats(self, group: Vec<StatGroup>, types: Vec<StatType>) -> Self {
+        self.with(Hydrate::Stats { group, types })
+    }
+
+    pub fn broadcasts(self) -> Self {
+        self.with(Hydrate::Broadcasts)
+    }
+
+    pub fn weather(self) -> Self {
+        self.with(Hydrate::Weather)
+    }
+
+    /// Venue of the game with nested hydrations (e.g. `VenueHydrations::new().timezone()`)
+    pub fn venue(self, nested: VenueHydrations) -> Self {
+        self.with(Hydrate::Venue(nested.hydrations))
+    }
+
+    /// Game level data with nested hydrations (e.g. `GameHydrations::new().content()`)
+    pub fn game(self, nested: GameHydrations) -> Self {
+        self.with(Hydrate::Game(nested.hydrations))
+    }
+
+    /// Postseason series record of the game
+    pub fn series_status(self) -> Self {
+        self.with(Hydrate::SeriesStatus)
+    }
+
+    pub fn flags(self) -> Self {
+        self.with(Hydrate::Flags)
+    }
+
+    pub fn officials(self) -> Self {
+        self.with(Hydrate::Officials)
+    }
+}
+
+impl Hydrations<StandingsEndpoint> {
+    pub fn team(self) -> Self {
+        self.with(Hydrate::Team)
+    }
+
+    pub fn league(self) -> Self {
+        self.with(Hydrate::League)
+    }
+
+    pub fn division(self) -> Self {
+        self.with(Hydrate::Division)
+    }
+
+    pub fn sport(self) -> Self {
+        self.with(Hydrate::Sport)
+    }
+
+    pub fn conference(self) -> Self {
+        self.with(Hydrate::Conference)
+    }
+}
+
+impl Hydrations<RosterEndpoint> {
+    /// Full details of each player (e.g. handedness and primary position)
+    pub fn person(self) -> Self {
+        self.with(Hydrate::Person)
+    }
+}
+
+impl Hydrations<VenueHydration> {
+    pub fn location(self) -> Self {
+        self.with(Hydrate::Location)
+    }
+
+    pub fn timezone(self) -> Self {
+        self.with(Hydrate::Timezone)
+    }
+}
+
+impl Hydrations<GameHydration> {
+    pub fn content(self) -> Self {
+        self.with(Hydrate::Content)
+    }
+}
+
+fn write_nested(
+    f: &mut std::fmt::Formatter<'_>,
+    name: &str,
+    nested: &[Hydrate],
+) -> std::fmt::Result {
+    if nested.is_empty() {
+        write!(f, "{}", name)
+    } else {
+        write!(f, "{}({})", name, join(nested.iter()))
+    }
+}
+
+fn join<T: Display>(items: impl Iterator<Item = T>) -> String {
+    items.map(|i| i.to_string()).collect::<Vec<_>>().join(",")
+}
+
+// Name of a value as it's serialized in query params, so stat names match QueryParams
+fn serde_name<T: Serialize>(value: &T) -> String {
+    #[derive(Serialize)]
+    struct Param<'a, T> {
+        v: &'a T,
+    }
+    serde_url_params::to_string(&Param { v: value })
+        .ok()
+        .and_then(|param| param.strip_prefix("v=").map(String::from))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schedule_hydrations() {
+        let hydrate = ScheduleHydrations::new().team().linescore();
+        assert_eq!(hydrate.to_string(), "team,linescore");
+
+        let hydrate = ScheduleHydrations::new()
+            .probable_pitcher()
+            .venue(VenueHydrations::new().location().timezone())
+            .game(GameHydrations::new().content())
+            .stats(vec![StatGroup::Pitching], vec![StatType::Season])
+            .team();
+        assert_eq!(
+            hydrate.to_string(),
+            "probablePitcher,venue(location,timezone),game(content),\
+             stats(group=[pitching],type=[season]),team"
+        );
+
+        let hydrate = ScheduleHydrations::new()
+            .venue(VenueHydrations::new())
+            .stats(
+                vec![StatGroup::Hitting, StatGroup::Fielding],
+                vec![StatType::YearByYear, StatType::LastXGames],
+            );
+        assert_eq!(
+            hydrate.to_string(),
+            "venue,stats(group=[hitting,fielding],type=[yearByYear,lastXGames])"
+        );
+    }
+
+    #[test]
+    fn duplicate_hydrations() {
+        let hydrate = StandingsHydrations::new().team().division().team();
+        assert_eq!(hydrate.to_string(), "team,division");
+        assert!(StandingsHydrations::new().is_empty());
+        assert_eq!(RosterHydrations::new().person().person().to_string(), "person");
+    }
+}
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::Player;
+
+/// Non-player jobs available through `v1/jobs/{type}`
+#[derive(PartialEq, Debug, Clone)]
+pub enum JobType {
+    Umpires,
+    Datacasters,
+    OfficialScorers,
+}
+
+impl JobType {
+    /// Returns the endpoint path for the job type (e.g. v1/jobs/umpires)
+    pub fn path(&self) -> String {
+        let name = match self {
+            JobType::Umpires => "umpires",
+            JobType::Datacasters => "datacasters",
+            JobType::OfficialScorers => "officialScorers",
+        };
+        format!("v1/jobs/{}", name)
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct JobsResponse {
+    pub roster: Vec<Job>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CoachesResponse {
+    /// Coaching staff of the team
+    pub roster: Vec<Job>,
+    /// Path to the coaches endpoint
+    pub link: String,
+    pub team_id: u32,
+    pub roster_type: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Job {
+    /// Person holding the job, the id can be joined against game officials
+    pub person: Player,
+    pub jersey_number: String,
+    /// Name of the job (e.g. Umpire, Manager)
+    pub job: String,
+    /// Short code of the job (e.g. UMPR, MNGR)
+    pub job_code: String,
+    /// Title of the job (e.g. Crew Chief, Bench Coach)
+    pub title: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn job_paths() {
+        assert_eq!(JobType::Umpires.path(), "v1/jobs/umpires");
+        assert_eq!(JobType::OfficialScorers.path(), "v1/jobs/officialScorers");
+    }
+
+    #[test]
+    fn deserialize_umpires() {
+        let umpires: JobsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/jobs_umpires.json")).unwrap();
+        assert_eq!(umpires.roster.len(), 3);
+        assert_eq!(umpires.roster[0].person.id, 427044);
+        assert_eq!(umpires.roster[0].job_code, "UMPR");
+    }
+}
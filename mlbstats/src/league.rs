@@ -1,8 +1,53 @@
 use std::str::FromStr;
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-use crate::error::MLBStatsError;
+use crate::{error::MLBStatsError, types::IdNameLink};
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct LeaguesResponse {
+    pub leagues: Vec<League>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct League {
+    /// Unique id for the league, used as the `leagueId` query param
+    pub id: u32,
+    /// Name of the league (e.g. International League)
+    pub name: String,
+    /// Path to the league endpoint
+    pub link: String,
+    /// Abbreviation of the league (e.g. IL)
+    pub abbreviation: String,
+    /// Shortened name (e.g. International)
+    pub name_short: String,
+    /// Current state of the season (e.g. offseason)
+    pub season_state: String,
+    pub has_wild_card: bool,
+    pub has_split_season: bool,
+    pub num_games: Option<u32>,
+    pub num_teams: Option<u32>,
+    pub num_wildcard_teams: Option<u32>,
+    /// Season year
+    pub season: String,
+    pub org_code: String,
+    pub conferences_in_use: bool,
+    pub divisions_in_use: bool,
+    /// Sport (level of play) the league belongs to
+    pub sport: IdNameLink,
+    pub sort_order: u32,
+    pub active: bool,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
 
 #[derive(PartialEq, Debug, Clone)]
 pub enum MLBLeague {
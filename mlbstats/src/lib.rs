@@ -1,14 +1,29 @@
+pub mod attendance;
 pub mod client;
+pub mod clinching;
 pub mod division;
+pub mod elo;
 pub mod error;
+pub mod fields;
+pub mod franchise;
 pub mod games;
+pub mod hydrate;
+pub mod jobs;
 pub mod league;
 pub mod linescore;
+pub mod meta;
+pub mod pagination;
 pub mod params;
 pub mod players;
+pub mod playoffs;
 pub mod plays;
+pub mod postseason;
+pub mod roster;
 pub mod schedule;
+pub mod simulation;
+pub mod sports;
 pub mod standings;
 pub mod stats;
 pub mod teams;
 pub mod types;
+pub mod venue;
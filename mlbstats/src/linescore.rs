@@ -1,8 +1,8 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-use crate::players::Player;
+use crate::players::{Player, Position};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Linescore {
     pub current_inning: u8,
@@ -18,34 +18,50 @@ pub struct Linescore {
     pub balls: u8,
     pub strikes: u8,
     pub outs: u8,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Inning {
     pub num: u8,
     pub ordinal_num: String,
     pub home: RunsHitsErrorsLOB,
     pub away: RunsHitsErrorsLOB,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Score {
     pub away: RunsHitsErrorsLOB,
     pub home: RunsHitsErrorsLOB,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct RunsHitsErrorsLOB {
     pub runs: u8, 
     pub hits: u8, 
     pub errors: u8, 
     pub left_on_base: u8, 
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Defense {
     pub pitcher: Player,
@@ -60,13 +76,39 @@ pub struct Defense {
     pub batter: Player,
     pub on_deck: Player,
     pub in_hole: Player,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl Defense {
+    /// Player in the field at a position, None for positions without a fielder (e.g. DH)
+    pub fn at(&self, position: Position) -> Option<&Player> {
+        match position {
+            Position::Pitcher => Some(&self.pitcher),
+            Position::Catcher => Some(&self.catcher),
+            Position::FirstBase => Some(&self.first),
+            Position::SecondBase => Some(&self.second),
+            Position::ThirdBase => Some(&self.third),
+            Position::Shortstop => Some(&self.shortstop),
+            Position::LeftField => Some(&self.left),
+            Position::CenterField => Some(&self.center),
+            Position::RightField => Some(&self.right),
+            _ => None,
+        }
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Offense {
     pub batter: Player,
     pub on_deck: Player,
     pub in_hole: Player,
     pub pitcher: Player,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
@@ -0,0 +1,283 @@
+use serde::{Deserialize, Serialize};
+
+/// Lookup tables exposed by the statsapi at `v1/{type}`. These are the canonical values for many
+/// of the params and enums used throughout the crate.
+#[derive(PartialEq, Debug, Clone)]
+pub enum MetaType {
+    GameTypes,
+    GameStatus,
+    StatTypes,
+    StatGroups,
+    LeagueLeaderTypes,
+    HitTrajectories,
+    PitchTypes,
+    PitchCodes,
+    Positions,
+    WindDirection,
+    SkyDescriptions,
+    EventTypes,
+    StandingsTypes,
+    RosterTypes,
+}
+
+impl MetaType {
+    /// Returns the endpoint path for the meta type (e.g. v1/gameTypes)
+    pub fn path(&self) -> String {
+        let name = match self {
+            MetaType::GameTypes => "gameTypes",
+            MetaType::GameStatus => "gameStatus",
+            MetaType::StatTypes => "statTypes",
+            MetaType::StatGroups => "statGroups",
+            MetaType::LeagueLeaderTypes => "leagueLeaderTypes",
+            MetaType::HitTrajectories => "hitTrajectories",
+            MetaType::PitchTypes => "pitchTypes",
+            MetaType::PitchCodes => "pitchCodes",
+            MetaType::Positions => "positions",
+            MetaType::WindDirection => "windDirection",
+            MetaType::SkyDescriptions => "skyDescriptions",
+            MetaType::EventTypes => "eventTypes",
+            MetaType::StandingsTypes => "standingsTypes",
+            MetaType::RosterTypes => "rosterTypes",
+        };
+        format!("v1/{}", name)
+    }
+}
+
+/// Typed list returned from a meta endpoint, the variant matches the requested MetaType
+#[derive(Debug, Clone)]
+pub enum MetaList {
+    GameTypes(Vec<GameTypeMeta>),
+    GameStatus(Vec<GameStatusMeta>),
+    StatTypes(Vec<DisplayName>),
+    StatGroups(Vec<DisplayName>),
+    LeagueLeaderTypes(Vec<DisplayName>),
+    HitTrajectories(Vec<CodeDescription>),
+    PitchTypes(Vec<CodeDescription>),
+    PitchCodes(Vec<PitchCodeMeta>),
+    Positions(Vec<PositionMeta>),
+    WindDirection(Vec<CodeDescription>),
+    SkyDescriptions(Vec<CodeDescription>),
+    EventTypes(Vec<EventTypeMeta>),
+    StandingsTypes(Vec<StandingsTypeMeta>),
+    RosterTypes(Vec<RosterTypeMeta>),
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameTypeMeta {
+    /// Game type code (e.g. R)
+    pub id: String,
+    /// Description of the game type (e.g. Regular Season)
+    pub description: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct GameStatusMeta {
+    pub abstract_game_state: String,
+    pub coded_game_state: String,
+    pub detailed_state: String,
+    pub status_code: String,
+    pub reason: Option<String>,
+    pub abstract_game_code: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Meta entry which only carries the value used in queries (e.g. statTypes, statGroups)
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct DisplayName {
+    pub display_name: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct CodeDescription {
+    pub code: String,
+    pub description: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PitchCodeMeta {
+    pub code: String,
+    pub description: String,
+    pub swing_status: bool,
+    pub swing_miss: bool,
+    pub swing_contact: bool,
+    pub sort_order: u32,
+    pub strike_status: bool,
+    pub ball_status: bool,
+    pub pitch_status: bool,
+    pub pitch_result_text: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PositionMeta {
+    pub short_name: String,
+    pub full_name: String,
+    pub abbrev: String,
+    pub code: String,
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub formal_name: String,
+    pub display_name: String,
+    pub outfield: bool,
+    pub game_position: bool,
+    pub pitcher: bool,
+    pub fielder: bool,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct EventTypeMeta {
+    pub plate_appearance: bool,
+    pub hit_event: bool,
+    pub base_running_event: bool,
+    pub description: String,
+    pub code: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct StandingsTypeMeta {
+    pub name: String,
+    pub description: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RosterTypeMeta {
+    pub description: String,
+    pub lookup_name: String,
+    pub parameter: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{games::GameTypes, standings::StandingType};
+    use serde::{
+        de::{value::Error, Error as _, Visitor},
+        forward_to_deserialize_any, Deserializer,
+    };
+
+    fn fixture<T: for<'de> Deserialize<'de>>(json: &str) -> Vec<T> {
+        serde_json::from_str(json).unwrap()
+    }
+
+    // Serde names of every variant of an enum, captured from the names its derived Deserialize
+    // passes to deserialize_enum
+    fn variants<T: for<'de> Deserialize<'de>>() -> &'static [&'static str] {
+        struct Variants<'a>(&'a mut &'static [&'static str]);
+
+        impl<'de, 'a> Deserializer<'de> for Variants<'a> {
+            type Error = Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                Err(Error::custom("not an enum"))
+            }
+
+            fn deserialize_enum<V: Visitor<'de>>(
+                self,
+                _: &'static str,
+                variants: &'static [&'static str],
+                _: V,
+            ) -> Result<V::Value, Error> {
+                *self.0 = variants;
+                Err(Error::custom("variants captured"))
+            }
+
+            forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes
+                byte_buf option unit unit_struct newtype_struct seq tuple tuple_struct map struct
+                identifier ignored_any
+            }
+        }
+
+        let mut variants = &[][..];
+        let _ = T::deserialize(Variants(&mut variants));
+        assert!(!variants.is_empty(), "not an enum");
+        variants
+    }
+
+    #[test]
+    fn game_types_match_meta() {
+        let meta: Vec<GameTypeMeta> = fixture(include_str!("../tests/fixtures/meta/gameTypes.json"));
+        assert!(!meta.is_empty());
+        for entry in &meta {
+            let game_type: GameTypes = serde_json::from_value(entry.id.clone().into())
+                .unwrap_or_else(|_| {
+                    panic!("{} ({}) is not a GameTypes", entry.id, entry.description)
+                });
+            assert_eq!(serde_json::to_value(game_type).unwrap(), entry.id);
+        }
+        for variant in variants::<GameTypes>() {
+            assert!(
+                meta.iter().any(|entry| entry.id == *variant),
+                "{} is not in the meta game types",
+                variant
+            );
+        }
+    }
+
+    #[test]
+    fn standing_types_match_meta() {
+        let meta: Vec<StandingsTypeMeta> =
+            fixture(include_str!("../tests/fixtures/meta/standingsTypes.json"));
+        assert!(!meta.is_empty());
+        for entry in &meta {
+            let parsed: Result<StandingType, _> = serde_json::from_value(entry.name.clone().into());
+            assert!(parsed.is_ok(), "{} is not a StandingType", entry.name);
+        }
+        for variant in variants::<StandingType>() {
+            assert!(
+                meta.iter().any(|entry| entry.name == *variant),
+                "{} is not in the meta standings types",
+                variant
+            );
+        }
+    }
+
+    #[test]
+    fn meta_paths() {
+        assert_eq!(MetaType::GameTypes.path(), "v1/gameTypes");
+        assert_eq!(MetaType::WindDirection.path(), "v1/windDirection");
+    }
+}
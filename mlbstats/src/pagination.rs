@@ -0,0 +1,148 @@
+use std::future::Future;
+
+use futures_util::{future::join_all, stream, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+use crate::{
+    error::MLBStatsError,
+    stats::{
+        player_stats::{StatSplit, StatsResponse},
+        stats_leaders::{Leader, StatsLeadersResponse},
+    },
+};
+
+/// Response of an endpoint that caps results with the limit and offset params
+pub trait Paginated: DeserializeOwned {
+    type Item;
+
+    /// Consumes the page, returning the individual items of the page
+    fn into_items(self) -> Vec<Self::Item>;
+}
+
+impl<S> Paginated for StatsResponse<S>
+where
+    S: DeserializeOwned + Default,
+{
+    type Item = StatSplit<S>;
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.stats.into_iter().flat_map(|s| s.splits).collect()
+    }
+}
+
+/// Leaders are flattened across categories, paginate a single leader category at a time
+impl Paginated for StatsLeadersResponse {
+    type Item = Leader;
+
+    fn into_items(self) -> Vec<Self::Item> {
+        self.league_leaders
+            .into_iter()
+            .flat_map(|c| c.leaders)
+            .collect()
+    }
+}
+
+/// Controls how pages are requested when paginating
+#[derive(Debug, Clone)]
+pub struct PageOptions {
+    /// Number of items requested per page, sent as the limit param
+    pub page_size: u32,
+    /// Number of pages requested at the same time
+    pub concurrency: usize,
+}
+
+impl Default for PageOptions {
+    fn default() -> Self {
+        Self {
+            page_size: 100,
+            concurrency: 1,
+        }
+    }
+}
+
+// Walks the offset from `start` in batches of `concurrency` pages until a page comes back short
+// or a request fails. Errors are yielded once and end the stream.
+pub(crate) fn paginate_pages<T, F, Fut>(
+    start: u32,
+    options: PageOptions,
+    fetch: F,
+) -> impl Stream<Item = Result<T, MLBStatsError>>
+where
+    F: Fn(u32, u32) -> Fut,
+    Fut: Future<Output = Result<Vec<T>, MLBStatsError>>,
+{
+    let page_size = options.page_size.max(1);
+    let concurrency = options.concurrency.max(1) as u32;
+
+    stream::unfold(Some(start), move |offset| {
+        let pages = offset.map(|offset| {
+            join_all((0..concurrency).map(|page| fetch(offset + page * page_size, page_size)))
+        });
+        async move {
+            let offset = offset?;
+            let mut next = Some(offset + concurrency * page_size);
+            let mut items = vec![];
+            for page in pages?.await {
+                match page {
+                    Ok(page) => {
+                        if (page.len() as u32) < page_size {
+                            next = None;
+                        }
+                        items.extend(page.into_iter().map(Ok));
+                    }
+                    Err(e) => {
+                        items.push(Err(e));
+                        next = None;
+                        break;
+                    }
+                }
+                if next.is_none() {
+                    break;
+                }
+            }
+            Some((stream::iter(items), next))
+        }
+    })
+    .flatten()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    async fn collect(options: PageOptions, total: u32) -> Vec<u32> {
+        paginate_pages(0, options, |offset, limit| async move {
+            Ok((offset..total.min(offset + limit)).collect())
+        })
+        .map(|item| item.unwrap())
+        .collect()
+        .await
+    }
+
+    #[tokio::test]
+    async fn walks_offsets_until_exhausted() {
+        let options = PageOptions {
+            page_size: 10,
+            concurrency: 1,
+        };
+        assert_eq!(collect(options, 35).await, (0..35).collect::<Vec<_>>());
+
+        let options = PageOptions {
+            page_size: 10,
+            concurrency: 3,
+        };
+        assert_eq!(collect(options, 40).await, (0..40).collect::<Vec<_>>());
+    }
+
+    #[tokio::test]
+    async fn stops_after_error() {
+        let items: Vec<Result<u32, MLBStatsError>> =
+            paginate_pages(0, PageOptions::default(), |_, _| async {
+                Err(MLBStatsError::MLBConversionError("page".into(), "0".into()))
+            })
+            .collect()
+            .await;
+        assert_eq!(items.len(), 1);
+        assert!(items[0].is_err());
+    }
+}
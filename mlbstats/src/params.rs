@@ -1,26 +1,49 @@
 #![allow(non_snake_case)]
 use serde::Serialize;
 
-use crate::{league::MLBLeague, stats::types::{LeagueLeaderTypes, StatGroup}, games::GameTypes, teams::MLBTeam};
+use crate::{stats::types::{LeagueLeaderTypes, PlayerPool, SortOrder, StatGroup, StatType}, games::GameTypes, fields::Fields, players::Position, roster::RosterType, standings::StandingType};
 
 /// Stores available params for the MLB API. Not all params are used/available for every endpoint
 /// check the endpoint to see available options.
 /// Should not need to be directly used unless constructing your own queries.
+///
+/// League and team ids are raw ids so that non-MLB leagues and teams can be queried, `MLBLeague`
+/// and `MLBTeam` convert with `.id()` or `.into()`.
 #[derive(Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct QueryParams {
-    pub league_id: Option<Vec<MLBLeague>>,
+    pub league_id: Option<Vec<u32>>,
     pub date: Option<String>,
     pub season: Option<String>,
-    pub standings_types: Option<Vec<String>>,
-    pub fields: Option<Vec<String>>,
+    pub standings_types: Option<Vec<StandingType>>,
+    /// Serialized comma separated (e.g. "dates,games,gamePk")
+    pub fields: Option<Fields>,
     pub hydrate: Option<String>,
     pub sport_id: u32,
     pub leader_categories: Option<Vec<LeagueLeaderTypes>>,
     pub leader_game_types: Option<Vec<GameTypes>>,
     pub stat_group: Option<StatGroup>,
     pub limit: Option<u32>,
-    pub team_id: Option<MLBTeam>,
+    pub team_id: Option<Vec<u32>>,
+    pub game_type: Option<Vec<GameTypes>>,
+    pub game_types: Option<Vec<GameTypes>>,
+    pub stats: Option<StatType>,
+    pub group: Option<StatGroup>,
+    pub player_pool: Option<PlayerPool>,
+    /// Serialized as the abbreviation (e.g. SS)
+    pub position: Option<Position>,
+    pub sort_stat: Option<LeagueLeaderTypes>,
+    pub order: Option<SortOrder>,
+    pub offset: Option<u32>,
+    /// Comma separated situation codes (e.g. "vl,h")
+    pub sit_codes: Option<String>,
+    pub start_date: Option<String>,
+    pub end_date: Option<String>,
+    pub opponent_id: Option<u32>,
+    pub venue_ids: Option<Vec<u32>>,
+    /// Time zone of returned times (e.g. America/New_York)
+    pub time_zone: Option<String>,
+    pub roster_type: Option<RosterType>,
 }
 
 impl Default for QueryParams {
@@ -38,6 +61,22 @@ impl Default for QueryParams {
             stat_group: Default::default(),
             limit: Default::default(),
             team_id: Default::default(),
+            game_type: Default::default(),
+            game_types: Default::default(),
+            stats: Default::default(),
+            group: Default::default(),
+            player_pool: Default::default(),
+            position: Default::default(),
+            sort_stat: Default::default(),
+            order: Default::default(),
+            offset: Default::default(),
+            sit_codes: Default::default(),
+            start_date: Default::default(),
+            end_date: Default::default(),
+            opponent_id: Default::default(),
+            venue_ids: Default::default(),
+            time_zone: Default::default(),
+            roster_type: Default::default(),
         }
     }
 }
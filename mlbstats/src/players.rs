@@ -1,7 +1,12 @@
-use serde::Deserialize;
+use std::{fmt::Display, str::FromStr};
 
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+use crate::{error::MLBStatsError, types::optional_date};
+
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Player {
     pub id: u32,
@@ -9,23 +14,371 @@ pub struct Player {
     pub link: String,
     pub first_name: String,
     pub last_name: String,
-    pub primary_number: u32,
-    pub birth_date: String,
+    /// Uniform number, a string since numbers can have leading zeros (e.g. 00)
+    pub primary_number: String,
+    #[serde(with = "optional_date")]
+    pub birth_date: Option<NaiveDate>,
     pub height: String,
     pub weight: String,
     pub active: bool,
     pub captain: bool,
     //TODO: stats, have different types so maybe have to do enum?
     //pub stats: Vec<Stat>,
-    /// Date format "YYYY-MM-DD"
-    pub mlb_debut_date: String,
+    #[serde(with = "optional_date")]
+    pub mlb_debut_date: Option<NaiveDate>,
     pub bat_side: Side,
     pub pitch_hand: Side,
+    pub primary_position: PlayerPosition,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl Player {
+    /// Side of the plate the player bats from, None when not returned
+    pub fn bats(&self) -> Option<Handedness> {
+        self.bat_side.hand()
+    }
+
+    /// Hand the player throws with, None when not returned
+    pub fn throws(&self) -> Option<Handedness> {
+        self.pitch_hand.hand()
+    }
+
+    /// Primary position of the player, None when not returned
+    pub fn position(&self) -> Option<Position> {
+        self.primary_position.position()
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Side {
-    code: String,
-    description: String,
+    /// Code of the side (e.g. L)
+    pub code: String,
+    /// Name of the side (e.g. Left)
+    pub description: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl Side {
+    pub fn hand(&self) -> Option<Handedness> {
+        self.code.parse().ok()
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PlayerPosition {
+    /// Scoring code of the position (e.g. 6)
+    pub code: String,
+    /// Name of the position (e.g. Shortstop)
+    pub name: String,
+    /// Type of position (e.g. Infielder)
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Abbreviation of the position (e.g. SS)
+    pub abbreviation: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl PlayerPosition {
+    pub fn position(&self) -> Option<Position> {
+        self.abbreviation.parse().ok()
+    }
+}
+
+/// Hand a player bats or throws with
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Handedness {
+    Left,
+    Right,
+    /// Bats from both sides of the plate
+    Switch,
+}
+
+impl Handedness {
+    pub fn code(&self) -> &'static str {
+        match self {
+            Handedness::Left => "L",
+            Handedness::Right => "R",
+            Handedness::Switch => "S",
+        }
+    }
+}
+
+impl FromStr for Handedness {
+    type Err = MLBStatsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "l" | "left" => Handedness::Left,
+            "r" | "right" => Handedness::Right,
+            "s" | "switch" => Handedness::Switch,
+            _ => {
+                return Err(MLBStatsError::MLBConversionError(
+                    "Handedness".into(),
+                    s.into(),
+                ))
+            }
+        })
+    }
+}
+
+impl Display for Handedness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let hand = match self {
+            Handedness::Left => "Left",
+            Handedness::Right => "Right",
+            Handedness::Switch => "Switch",
+        };
+        write!(f, "{}", hand)
+    }
+}
+
+/// Positions a player can be listed at, serialized as the abbreviation (e.g. SS)
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Position {
+    Pitcher,
+    Catcher,
+    FirstBase,
+    SecondBase,
+    ThirdBase,
+    Shortstop,
+    LeftField,
+    CenterField,
+    RightField,
+    DesignatedHitter,
+    TwoWayPlayer,
+    Outfield,
+    Infield,
+    PinchHitter,
+    PinchRunner,
+}
+
+/// Group a position belongs to, matches the type of a position returned by the API
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PositionType {
+    Pitcher,
+    Catcher,
+    Infielder,
+    Outfielder,
+    Hitter,
+    Runner,
+    TwoWayPlayer,
+}
+
+impl Position {
+    /// Scoring code of the position (e.g. 6 for Shortstop)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Position::Pitcher => "1",
+            Position::Catcher => "2",
+            Position::FirstBase => "3",
+            Position::SecondBase => "4",
+            Position::ThirdBase => "5",
+            Position::Shortstop => "6",
+            Position::LeftField => "7",
+            Position::CenterField => "8",
+            Position::RightField => "9",
+            Position::DesignatedHitter => "10",
+            Position::PinchHitter => "11",
+            Position::PinchRunner => "12",
+            Position::TwoWayPlayer => "Y",
+            Position::Outfield => "O",
+            Position::Infield => "I",
+        }
+    }
+
+    pub fn abbreviation(&self) -> &'static str {
+        match self {
+            Position::Pitcher => "P",
+            Position::Catcher => "C",
+            Position::FirstBase => "1B",
+            Position::SecondBase => "2B",
+            Position::ThirdBase => "3B",
+            Position::Shortstop => "SS",
+            Position::LeftField => "LF",
+            Position::CenterField => "CF",
+            Position::RightField => "RF",
+            Position::DesignatedHitter => "DH",
+            Position::TwoWayPlayer => "TWP",
+            Position::Outfield => "OF",
+            Position::Infield => "IF",
+            Position::PinchHitter => "PH",
+            Position::PinchRunner => "PR",
+        }
+    }
+
+    pub fn kind(&self) -> PositionType {
+        match self {
+            Position::Pitcher => PositionType::Pitcher,
+            Position::Catcher => PositionType::Catcher,
+            Position::FirstBase
+            | Position::SecondBase
+            | Position::ThirdBase
+            | Position::Shortstop
+            | Position::Infield => PositionType::Infielder,
+            Position::LeftField
+            | Position::CenterField
+            | Position::RightField
+            | Position::Outfield => PositionType::Outfielder,
+            Position::DesignatedHitter | Position::PinchHitter => PositionType::Hitter,
+            Position::PinchRunner => PositionType::Runner,
+            Position::TwoWayPlayer => PositionType::TwoWayPlayer,
+        }
+    }
+}
+
+impl FromStr for Position {
+    type Err = MLBStatsError;
+
+    /// Parses an abbreviation (e.g. SS) or scoring code (e.g. 6)
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_uppercase().as_str() {
+            "P" | "1" => Position::Pitcher,
+            "C" | "2" => Position::Catcher,
+            "1B" | "3" => Position::FirstBase,
+            "2B" | "4" => Position::SecondBase,
+            "3B" | "5" => Position::ThirdBase,
+            "SS" | "6" => Position::Shortstop,
+            "LF" | "7" => Position::LeftField,
+            "CF" | "8" => Position::CenterField,
+            "RF" | "9" => Position::RightField,
+            "DH" | "10" => Position::DesignatedHitter,
+            "PH" | "11" => Position::PinchHitter,
+            "PR" | "12" => Position::PinchRunner,
+            "TWP" | "Y" => Position::TwoWayPlayer,
+            "OF" | "O" => Position::Outfield,
+            "IF" | "I" => Position::Infield,
+            _ => {
+                return Err(MLBStatsError::MLBConversionError(
+                    "Position".into(),
+                    s.into(),
+                ))
+            }
+        })
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.abbreviation())
+    }
+}
+
+impl Serialize for Position {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.abbreviation())
+    }
+}
+
+/// Roster status of a player, parsed from the status code of a roster entry
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub enum PlayerStatus {
+    Active,
+    /// Injured list, with the minimum number of days (7, 10, 15 or 60)
+    InjuredList(u32),
+    ReassignedToMinors,
+    /// Optioned or assigned to a minor league club
+    MinorLeagues,
+    Paternity,
+    Bereavement,
+    Suspended,
+    Restricted,
+    NonRosterInvitee,
+    Released,
+    Other(String),
+}
+
+impl PlayerStatus {
+    /// Whether the player counts against the active roster
+    pub fn is_active(&self) -> bool {
+        *self == PlayerStatus::Active
+    }
+}
+
+impl From<&str> for PlayerStatus {
+    fn from(value: &str) -> Self {
+        match value {
+            "A" => PlayerStatus::Active,
+            "D7" => PlayerStatus::InjuredList(7),
+            "D10" => PlayerStatus::InjuredList(10),
+            "D15" => PlayerStatus::InjuredList(15),
+            "D60" => PlayerStatus::InjuredList(60),
+            "RM" => PlayerStatus::ReassignedToMinors,
+            "MIN" => PlayerStatus::MinorLeagues,
+            "PL" => PlayerStatus::Paternity,
+            "BRV" => PlayerStatus::Bereavement,
+            "SU" => PlayerStatus::Suspended,
+            "RST" => PlayerStatus::Restricted,
+            "NRI" => PlayerStatus::NonRosterInvitee,
+            "RL" => PlayerStatus::Released,
+            _ => PlayerStatus::Other(value.to_string()),
+        }
+    }
+}
+
+impl Display for PlayerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlayerStatus::Active => write!(f, "A"),
+            PlayerStatus::InjuredList(days) => write!(f, "D{}", days),
+            PlayerStatus::ReassignedToMinors => write!(f, "RM"),
+            PlayerStatus::MinorLeagues => write!(f, "MIN"),
+            PlayerStatus::Paternity => write!(f, "PL"),
+            PlayerStatus::Bereavement => write!(f, "BRV"),
+            PlayerStatus::Suspended => write!(f, "SU"),
+            PlayerStatus::Restricted => write!(f, "RST"),
+            PlayerStatus::NonRosterInvitee => write!(f, "NRI"),
+            PlayerStatus::Released => write!(f, "RL"),
+            PlayerStatus::Other(code) => write!(f, "{}", code),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn positions() {
+        for position in [
+            "P", "c", "1B", "6", "lf", "DH", "TWP", "Y", "OF", "IF", "PH", "PR",
+        ] {
+            let parsed: Position = position.parse().unwrap();
+            assert_eq!(parsed.abbreviation().parse::<Position>().unwrap(), parsed);
+            assert_eq!(parsed.code().parse::<Position>().unwrap(), parsed);
+        }
+        assert_eq!(Position::Shortstop.code(), "6");
+        assert_eq!(Position::Shortstop.kind(), PositionType::Infielder);
+        assert_eq!(Position::TwoWayPlayer.kind(), PositionType::TwoWayPlayer);
+        assert!("XX".parse::<Position>().is_err());
+    }
+
+    #[test]
+    fn handedness_and_status() {
+        let side = Side {
+            code: "S".into(),
+            ..Default::default()
+        };
+        assert_eq!(side.hand(), Some(Handedness::Switch));
+        assert_eq!(Side::default().hand(), None);
+
+        for code in ["A", "D10", "D60", "RM", "NRI", "XYZ"] {
+            assert_eq!(PlayerStatus::from(code).to_string(), code);
+        }
+        assert_eq!(PlayerStatus::from("D15"), PlayerStatus::InjuredList(15));
+        assert!(PlayerStatus::from("A").is_active());
+    }
 }
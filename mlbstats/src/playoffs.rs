@@ -0,0 +1,386 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use crate::{
+    games::GameTypes,
+    schedule::ScheduleResponse,
+    standings::{StandingsResponse, TeamRecord},
+    teams::Team,
+};
+
+/// Division winners with the best records skip the wild card series
+pub const BYES: usize = 2;
+/// Non division winners with the best records that make the postseason in each league
+pub const WILD_CARDS: usize = 3;
+
+/// Regular season wins and losses between teams, used to break ties in the playoff picture
+#[derive(Default, Debug, Clone)]
+pub struct HeadToHead {
+    wins: HashMap<(u32, u32), u32>,
+}
+
+impl HeadToHead {
+    /// Counts the regular season games won by each team in a schedule. Games without a winner
+    /// (e.g. not yet final) are skipped.
+    pub fn from_schedule(schedule: &ScheduleResponse) -> Self {
+        let mut head_to_head = HeadToHead::default();
+        for game in schedule.games() {
+            if game.game_type != GameTypes::R {
+                continue;
+            }
+            let (home, away) = (&game.teams.home, &game.teams.away);
+            match (home.is_winner, away.is_winner) {
+                (Some(true), _) => head_to_head.add_game(home.team.id, away.team.id),
+                (_, Some(true)) => head_to_head.add_game(away.team.id, home.team.id),
+                _ => {}
+            }
+        }
+        head_to_head
+    }
+
+    pub fn add_game(&mut self, winner_id: u32, loser_id: u32) {
+        *self.wins.entry((winner_id, loser_id)).or_default() += 1;
+    }
+
+    /// Wins and losses of a team against an opponent
+    pub fn record(&self, team_id: u32, opponent_id: u32) -> (u32, u32) {
+        let wins = |winner, loser| self.wins.get(&(winner, loser)).copied().unwrap_or_default();
+        (wins(team_id, opponent_id), wins(opponent_id, team_id))
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum Qualification {
+    DivisionWinner,
+    WildCard,
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayoffTeam {
+    pub team: Team,
+    pub division_id: u32,
+    pub wins: u32,
+    pub losses: u32,
+}
+
+impl PlayoffTeam {
+    pub fn winning_percentage(&self) -> f64 {
+        pct(self.wins, self.losses)
+    }
+
+    /// Games behind another team, negative when ahead of it
+    pub fn games_behind(&self, other: &PlayoffTeam) -> f64 {
+        ((other.wins as f64 - self.wins as f64) + (self.losses as f64 - other.losses as f64)) / 2.0
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PlayoffSeed {
+    pub seed: u32,
+    pub qualification: Qualification,
+    /// Whether the seed skips the wild card series
+    pub bye: bool,
+    pub team: PlayoffTeam,
+}
+
+/// Team outside of the field that hasn't been eliminated
+#[derive(Debug, Clone)]
+pub struct HuntTeam {
+    pub team: PlayoffTeam,
+    /// Games behind the last wild card
+    pub games_back: f64,
+}
+
+/// Postseason field of a league if the season ended today under the 12 team format: division
+/// winners are seeded first by record with the top two receiving byes, followed by three wild
+/// cards.
+///
+/// Ties are broken by the head to head record between the tied teams, then the record within the
+/// division, then the record within the league. Remaining ties fall back to the league rank from
+/// the API.
+///
+/// The record within the division comes from the standings split records. Those don't include
+/// records against individual teams, so head to head records are counted from the schedule
+/// instead, see HeadToHead::from_schedule.
+#[derive(Debug, Clone)]
+pub struct PlayoffPicture {
+    pub league_id: u32,
+    pub seeds: Vec<PlayoffSeed>,
+    /// Ordered by games back
+    pub hunt: Vec<HuntTeam>,
+}
+
+impl PlayoffPicture {
+    /// Builds the playoff picture of a league from regular season standings by division, the
+    /// head to head records can be empty
+    pub fn new(standings: &StandingsResponse, league_id: u32, head_to_head: &HeadToHead) -> Self {
+        let mut divisions: Vec<Vec<Entry>> = vec![];
+        for division in standings
+            .records
+            .iter()
+            .filter(|division| division.league.id == league_id)
+        {
+            divisions.push(
+                division
+                    .team_records
+                    .iter()
+                    .map(|record| Entry {
+                        record,
+                        division_id: division.division.id,
+                    })
+                    .collect(),
+            );
+        }
+
+        let mut winners = vec![];
+        let mut others = vec![];
+        for division in divisions {
+            let mut ranked = rank(division, head_to_head).into_iter();
+            winners.extend(ranked.next());
+            others.extend(ranked);
+        }
+
+        let mut seeds = vec![];
+        for entry in rank(winners, head_to_head) {
+            seeds.push(PlayoffSeed {
+                seed: seeds.len() as u32 + 1,
+                qualification: Qualification::DivisionWinner,
+                bye: seeds.len() < BYES,
+                team: entry.team(),
+            });
+        }
+
+        let mut others = rank(others, head_to_head).into_iter();
+        for entry in others.by_ref().take(WILD_CARDS) {
+            seeds.push(PlayoffSeed {
+                seed: seeds.len() as u32 + 1,
+                qualification: Qualification::WildCard,
+                bye: false,
+                team: entry.team(),
+            });
+        }
+
+        let last = seeds.last().map(|seed| seed.team.clone());
+        let hunt = others
+            .filter(|entry| !entry.record.eliminated())
+            .map(|entry| {
+                let team = entry.team();
+                HuntTeam {
+                    games_back: last.as_ref().map_or(0.0, |last| team.games_behind(last)),
+                    team,
+                }
+            })
+            .collect();
+
+        PlayoffPicture {
+            league_id,
+            seeds,
+            hunt,
+        }
+    }
+
+    pub fn seed(&self, team_id: u32) -> Option<&PlayoffSeed> {
+        self.seeds.iter().find(|seed| seed.team.team.id == team_id)
+    }
+
+    pub fn division_winners(&self) -> impl Iterator<Item = &PlayoffSeed> {
+        self.seeds
+            .iter()
+            .filter(|seed| seed.qualification == Qualification::DivisionWinner)
+    }
+
+    pub fn wild_cards(&self) -> impl Iterator<Item = &PlayoffSeed> {
+        self.seeds
+            .iter()
+            .filter(|seed| seed.qualification == Qualification::WildCard)
+    }
+}
+
+#[derive(Clone, Copy)]
+struct Entry<'a> {
+    record: &'a TeamRecord,
+    division_id: u32,
+}
+
+impl<'a> Entry<'a> {
+    fn pct(&self) -> f64 {
+        pct(self.record.wins, self.record.losses)
+    }
+
+    fn division_pct(&self) -> f64 {
+        self.record
+            .records
+            .vs_division(self.division_id)
+            .map_or(0.0, |record| pct(record.wins, record.losses))
+    }
+
+    fn league_pct(&self) -> f64 {
+        let record = &self.record.league_record;
+        pct(record.wins, record.losses)
+    }
+
+    fn team(&self) -> PlayoffTeam {
+        PlayoffTeam {
+            team: self.record.team.clone(),
+            division_id: self.division_id,
+            wins: self.record.wins,
+            losses: self.record.losses,
+        }
+    }
+}
+
+fn pct(wins: u32, losses: u32) -> f64 {
+    match wins + losses {
+        0 => 0.0,
+        games => wins as f64 / games as f64,
+    }
+}
+
+// Higher percentages sort first
+fn descending(a: f64, b: f64) -> Ordering {
+    b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+}
+
+// Orders teams by winning percentage and breaks ties within each group of tied teams
+fn rank<'a>(mut entries: Vec<Entry<'a>>, head_to_head: &HeadToHead) -> Vec<Entry<'a>> {
+    entries.sort_by(|a, b| descending(a.pct(), b.pct()));
+
+    let mut ranked = Vec::with_capacity(entries.len());
+    for tied in entries.chunk_by(|a, b| a.pct() == b.pct()) {
+        let mut tied = tied.to_vec();
+        if tied.len() > 1 {
+            // head to head percentage against the rest of the tied teams
+            let ids: Vec<u32> = tied.iter().map(|entry| entry.record.team.id).collect();
+            let h2h: HashMap<u32, f64> = ids
+                .iter()
+                .map(|&id| {
+                    let (wins, losses) = ids
+                        .iter()
+                        .filter(|&&opponent| opponent != id)
+                        .map(|&opponent| head_to_head.record(id, opponent))
+                        .fold((0, 0), |(w, l), (wins, losses)| (w + wins, l + losses));
+                    (
+                        id,
+                        if wins + losses == 0 {
+                            0.5
+                        } else {
+                            pct(wins, losses)
+                        },
+                    )
+                })
+                .collect();
+
+            tied.sort_by(|a, b| {
+                descending(h2h[&a.record.team.id], h2h[&b.record.team.id])
+                    .then_with(|| descending(a.division_pct(), b.division_pct()))
+                    .then_with(|| descending(a.league_pct(), b.league_pct()))
+                    .then_with(|| {
+                        let rank = |entry: &Entry| entry.record.league_rank.unwrap_or(u32::MAX);
+                        rank(a).cmp(&rank(b))
+                    })
+                    .then_with(|| a.record.team.id.cmp(&b.record.team.id))
+            });
+        }
+        ranked.extend(tied);
+    }
+    ranked
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn standings() -> StandingsResponse {
+        serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap()
+    }
+
+    fn seeds(picture: &PlayoffPicture) -> Vec<u32> {
+        picture.seeds.iter().map(|seed| seed.team.team.id).collect()
+    }
+
+    #[test]
+    fn playoff_picture() {
+        let picture = PlayoffPicture::new(&standings(), 103, &HeadToHead::default());
+        // BAL, HOU, MIN then the TB, TEX, TOR wild cards
+        assert_eq!(seeds(&picture), vec![110, 117, 142, 139, 140, 141]);
+        assert!(picture.seed(110).unwrap().bye);
+        assert!(picture.seed(117).unwrap().bye);
+        assert!(!picture.seed(142).unwrap().bye);
+        assert_eq!(picture.division_winners().count(), 3);
+        assert_eq!(
+            picture.seed(139).unwrap().qualification,
+            Qualification::WildCard
+        );
+        // every other team was eliminated by the end of the season
+        assert!(picture.hunt.is_empty());
+
+        assert!(
+            PlayoffPicture::new(&standings(), 104, &HeadToHead::default())
+                .seeds
+                .is_empty()
+        );
+    }
+
+    fn series(winner: u32, loser: u32, wins: u32, losses: u32) -> HeadToHead {
+        let mut head_to_head = HeadToHead::default();
+        (0..wins).for_each(|_| head_to_head.add_game(winner, loser));
+        (0..losses).for_each(|_| head_to_head.add_game(loser, winner));
+        head_to_head
+    }
+
+    #[test]
+    fn head_to_head_tiebreaker() {
+        // HOU and TEX finished 90-72 with the same division and league records, HOU won the
+        // season series 9-4 and with it the AL West
+        let head_to_head = series(117, 140, 9, 4);
+        assert_eq!(head_to_head.record(117, 140), (9, 4));
+
+        let picture = PlayoffPicture::new(&standings(), 103, &head_to_head);
+        assert_eq!(seeds(&picture), vec![110, 117, 142, 139, 140, 141]);
+        assert_eq!(
+            picture.seed(117).unwrap().qualification,
+            Qualification::DivisionWinner
+        );
+    }
+
+    #[test]
+    fn head_to_head_tiebreaker_hypothetical() {
+        // had TEX won the season series instead, TEX would have taken the division and the bye
+        let picture = PlayoffPicture::new(&standings(), 103, &series(140, 117, 9, 4));
+        assert_eq!(seeds(&picture), vec![110, 140, 142, 139, 117, 141]);
+        assert_eq!(
+            picture.seed(140).unwrap().qualification,
+            Qualification::DivisionWinner
+        );
+    }
+
+    #[test]
+    fn teams_in_the_hunt() {
+        let mut standings = standings();
+        for team in standings
+            .records
+            .iter_mut()
+            .flat_map(|record| record.team_records.iter_mut())
+            .filter(|team| team.team.id == 136)
+        {
+            team.elimination_number = None;
+            team.wild_card_elimination_number = None;
+        }
+
+        let picture = PlayoffPicture::new(&standings, 103, &HeadToHead::default());
+        assert_eq!(picture.hunt.len(), 1);
+        assert_eq!(picture.hunt[0].team.team.id, 136);
+        assert_eq!(picture.hunt[0].games_back, 1.0);
+    }
+
+    #[test]
+    fn head_to_head_from_schedule() {
+        let schedule: ScheduleResponse = serde_json::from_str(include_str!(
+            "../tests/fixtures/schedule_double_header.json"
+        ))
+        .unwrap();
+        let head_to_head = HeadToHead::from_schedule(&schedule);
+        assert_eq!(head_to_head.record(136, 140), (1, 1));
+        assert_eq!(head_to_head.record(139, 110), (1, 0));
+        assert_eq!(head_to_head.record(110, 139), (0, 1));
+    }
+}
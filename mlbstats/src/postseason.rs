@@ -0,0 +1,205 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    games::{Game, GameTypes},
+    teams::Team,
+};
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PostseasonResponse {
+    /// Number of games across every series
+    pub total_games: u32,
+    /// Series of the postseason, each with its games
+    pub series: Vec<SeriesGames>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SeriesGames {
+    pub series: SeriesInfo,
+    /// Number of games scheduled in the series so far
+    pub total_games: u32,
+    pub games: Vec<Game>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SeriesInfo {
+    /// Series identifier made of the round and series number (e.g. D_2)
+    pub id: String,
+    pub is_default: bool,
+    /// Round of the series
+    pub game_type: GameTypes,
+    pub sort_number: u32,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Postseason games grouped into series
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PostseasonBracket {
+    pub series: Vec<PostseasonSeries>,
+}
+
+impl PostseasonBracket {
+    /// Returns the series of a single round (e.g. GameTypes::D for the Division Series)
+    pub fn round(&self, round: GameTypes) -> Vec<&PostseasonSeries> {
+        self.series.iter().filter(|s| s.round == round).collect()
+    }
+}
+
+impl From<PostseasonResponse> for PostseasonBracket {
+    fn from(value: PostseasonResponse) -> Self {
+        let mut series: Vec<PostseasonSeries> = value
+            .series
+            .into_iter()
+            .map(PostseasonSeries::from)
+            .collect();
+        series.sort_by_key(|s| s.sort_number);
+        Self { series }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PostseasonSeries {
+    /// Series identifier (e.g. D_2)
+    pub id: String,
+    /// Round of the series, one of GameTypes::F, D, L or W
+    pub round: GameTypes,
+    /// Name of the round (e.g. AL Wild Card Series)
+    pub description: String,
+    pub sort_number: u32,
+    /// Maximum number of games in the series
+    pub best_of: u32,
+    /// Participating teams, the first team listed is the home team of game one
+    pub teams: Vec<SeriesTeam>,
+    /// Every game of the series including ones not yet played
+    pub games: Vec<Game>,
+}
+
+impl PostseasonSeries {
+    /// Number of wins required to clinch the series
+    pub fn wins_needed(&self) -> u32 {
+        self.best_of / 2 + 1
+    }
+
+    /// Returns the team that has clinched the series
+    pub fn winner(&self) -> Option<&SeriesTeam> {
+        self.teams.iter().find(|t| t.wins >= self.wins_needed())
+    }
+
+    /// Whether either team has clinched the series
+    pub fn is_over(&self) -> bool {
+        self.winner().is_some()
+    }
+
+    /// Returns the series record in W-L form from the perspective of the provided team
+    pub fn record(&self, team_id: u32) -> Option<(u32, u32)> {
+        let team = self.teams.iter().find(|t| t.team.id == team_id)?;
+        let opponent_wins = self
+            .teams
+            .iter()
+            .filter(|t| t.team.id != team_id)
+            .map(|t| t.wins)
+            .sum();
+        Some((team.wins, opponent_wins))
+    }
+}
+
+impl From<SeriesGames> for PostseasonSeries {
+    fn from(value: SeriesGames) -> Self {
+        let mut teams: Vec<SeriesTeam> = vec![];
+        for game in &value.games {
+            for game_team in [&game.teams.home, &game.teams.away] {
+                let team = match teams.iter_mut().find(|t| t.team.id == game_team.team.id) {
+                    Some(team) => team,
+                    None => {
+                        teams.push(SeriesTeam {
+                            team: game_team.team.clone(),
+                            seed: game_team.seed,
+                            wins: 0,
+                        });
+                        teams.last_mut().unwrap()
+                    }
+                };
+                if game_team.is_winner == Some(true) {
+                    team.wins += 1;
+                }
+            }
+        }
+
+        let round = value.series.game_type;
+        let first_game = value.games.first();
+        let best_of = match first_game.map(|g| g.games_in_series) {
+            Some(games) if games > 0 => games,
+            _ => default_series_length(&round),
+        };
+
+        Self {
+            id: value.series.id,
+            description: first_game
+                .map(|g| g.series_description.clone())
+                .unwrap_or_default(),
+            round,
+            sort_number: value.series.sort_number,
+            best_of,
+            teams,
+            games: value.games,
+        }
+    }
+}
+
+/// Team participating in a postseason series
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SeriesTeam {
+    pub team: Team,
+    pub seed: Option<u32>,
+    /// Games won in the series
+    pub wins: u32,
+}
+
+// Length of each round under the current postseason format
+fn default_series_length(round: &GameTypes) -> u32 {
+    match round {
+        GameTypes::F => 3,
+        GameTypes::D => 5,
+        _ => 7,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn bracket_from_series() {
+        let response: PostseasonResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/postseason_series.json")).unwrap();
+        let bracket = PostseasonBracket::from(response);
+        assert_eq!(bracket.series.len(), 2);
+
+        let wild_card = &bracket.round(GameTypes::F)[0];
+        assert_eq!(wild_card.best_of, 3);
+        assert_eq!(wild_card.winner().unwrap().team.id, 140);
+        assert_eq!(wild_card.record(139), Some((0, 2)));
+
+        let division_series = &bracket.round(GameTypes::D)[0];
+        assert_eq!(division_series.record(140), Some((2, 0)));
+        assert!(!division_series.is_over());
+        assert_eq!(division_series.games.len(), 3);
+    }
+}
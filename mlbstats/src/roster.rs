@@ -0,0 +1,105 @@
+use serde::{Deserialize, Serialize};
+
+use crate::players::{Player, PlayerPosition, PlayerStatus};
+
+/// Rosters available through the rosterType param, defaults to the active roster
+#[derive(Serialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum RosterType {
+    Active,
+    #[serde(rename = "40Man")]
+    FortyMan,
+    /// Every player on the roster at any point during the season
+    FullSeason,
+    /// Active roster plus injured and optioned players
+    FullRoster,
+    NonRosterInvitees,
+    DepthChart,
+    /// Every player to have played for the team
+    AllTime,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RosterResponse {
+    pub roster: Vec<RosterEntry>,
+    /// Path to the roster endpoint
+    pub link: String,
+    pub team_id: u32,
+    pub roster_type: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RosterEntry {
+    pub person: Player,
+    pub jersey_number: String,
+    /// Position the player is listed at on the roster
+    pub position: PlayerPosition,
+    pub status: RosterStatus,
+    /// Major league organization of the team
+    pub parent_team_id: u32,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct RosterStatus {
+    /// Status code (e.g. A, D60)
+    pub code: String,
+    /// Name of the status (e.g. Active, Injured 60-Day)
+    pub description: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl RosterStatus {
+    pub fn status(&self) -> PlayerStatus {
+        PlayerStatus::from(self.code.as_str())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::players::{Handedness, Position};
+
+    #[test]
+    fn deserialize_roster() {
+        let roster: RosterResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/roster_tex.json")).unwrap();
+        assert_eq!(roster.team_id, 140);
+        assert_eq!(roster.roster_type, "fullRoster");
+
+        let seager = roster
+            .roster
+            .iter()
+            .find(|entry| entry.person.id == 608369)
+            .unwrap();
+        assert_eq!(seager.position.position(), Some(Position::Shortstop));
+        assert_eq!(seager.person.position(), Some(Position::Shortstop));
+        assert_eq!(seager.person.bats(), Some(Handedness::Left));
+        assert_eq!(seager.person.throws(), Some(Handedness::Right));
+        assert!(seager.status.status().is_active());
+
+        let injured: Vec<&RosterEntry> = roster
+            .roster
+            .iter()
+            .filter(|entry| entry.status.status() == PlayerStatus::InjuredList(60))
+            .collect();
+        assert_eq!(injured.len(), 2);
+        assert!(roster
+            .roster
+            .iter()
+            .all(|entry| entry.position.position().is_some()));
+    }
+}
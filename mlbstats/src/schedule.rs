@@ -1,15 +1,197 @@
 #![allow(non_snake_case)]
-use serde::Deserialize;
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
-use crate::games::Game;
+use crate::{
+    client::option_date_to_string,
+    fields::Fields,
+    games::{DoubleHeader, Game, GameTypes},
+    hydrate::ScheduleHydrations,
+    params::QueryParams,
+    sports::Sport,
+    types::date,
+};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+/// Query for the schedule endpoint, defaults to today's MLB games and is narrowed with the
+/// builder methods
+#[derive(Debug, Clone)]
+pub struct ScheduleQuery {
+    sport: Sport,
+    league_id: Option<Vec<u32>>,
+    team_id: Vec<u32>,
+    opponent_id: Option<u32>,
+    date: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    season: Option<String>,
+    game_types: Vec<GameTypes>,
+    venue_ids: Vec<u32>,
+    time_zone: Option<String>,
+    double_header: Option<DoubleHeader>,
+    hydrate: Option<ScheduleHydrations>,
+    fields: Option<Fields>,
+}
+
+impl ScheduleQuery {
+    pub fn new() -> Self {
+        Self {
+            sport: Sport::MLB,
+            league_id: None,
+            team_id: vec![],
+            opponent_id: None,
+            date: None,
+            start_date: None,
+            end_date: None,
+            season: None,
+            game_types: vec![],
+            venue_ids: vec![],
+            time_zone: None,
+            double_header: None,
+            hydrate: None,
+            fields: None,
+        }
+    }
+
+    pub fn sport(mut self, sport: Sport) -> Self {
+        self.sport = sport;
+        self
+    }
+
+    pub fn leagues(mut self, league_id: Vec<u32>) -> Self {
+        self.league_id = Some(league_id);
+        self
+    }
+
+    /// Adds a team to the query, games of every added team are returned
+    pub fn team(mut self, team_id: u32) -> Self {
+        if !self.team_id.contains(&team_id) {
+            self.team_id.push(team_id);
+        }
+        self
+    }
+
+    pub fn teams(self, team_ids: Vec<u32>) -> Self {
+        team_ids.into_iter().fold(self, |query, id| query.team(id))
+    }
+
+    /// Only returns games against the provided team, used with team
+    pub fn opponent(mut self, team_id: u32) -> Self {
+        self.opponent_id = Some(team_id);
+        self
+    }
+
+    /// Single day of games, replaces any date range
+    pub fn date<D: Datelike>(mut self, date: D) -> Self {
+        self.date = option_date_to_string(Some(date));
+        self.start_date = None;
+        self.end_date = None;
+        self
+    }
+
+    /// Games from the start date through the end date (inclusive), replaces any single date
+    pub fn dates<D: Datelike>(mut self, start: D, end: D) -> Self {
+        self.date = None;
+        self.start_date = option_date_to_string(Some(start));
+        self.end_date = option_date_to_string(Some(end));
+        self
+    }
+
+    /// Every game of the season, narrowed by dates when both are provided
+    pub fn season(mut self, season: u32) -> Self {
+        self.season = Some(season.to_string());
+        self
+    }
+
+    pub fn game_types(mut self, game_types: Vec<GameTypes>) -> Self {
+        self.game_types = game_types;
+        self
+    }
+
+    pub fn venues(mut self, venue_ids: Vec<u32>) -> Self {
+        self.venue_ids = venue_ids;
+        self
+    }
+
+    /// Time zone the game times are returned in (e.g. America/New_York)
+    pub fn time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.into());
+        self
+    }
+
+    /// Only keeps games with the provided double header status. The API has no double header
+    /// param so games are filtered once the response is returned.
+    pub fn double_header(mut self, double_header: DoubleHeader) -> Self {
+        self.double_header = Some(double_header);
+        self
+    }
+
+    pub fn hydrate(mut self, hydrate: ScheduleHydrations) -> Self {
+        self.hydrate = Some(hydrate);
+        self
+    }
+
+    pub fn fields(mut self, fields: Fields) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Converts the query into the params sent to the schedule endpoint
+    pub fn params(&self) -> QueryParams {
+        // the double header status is needed to filter a response with selected fields
+        let fields = match (&self.fields, &self.double_header) {
+            (Some(fields), Some(_)) => Some(fields.clone().with("doubleHeader")),
+            (fields, _) => fields.clone(),
+        };
+        QueryParams {
+            sport_id: self.sport.id(),
+            league_id: self.league_id.clone(),
+            team_id: (!self.team_id.is_empty()).then(|| self.team_id.clone()),
+            opponent_id: self.opponent_id,
+            date: self.date.clone(),
+            start_date: self.start_date.clone(),
+            end_date: self.end_date.clone(),
+            season: self.season.clone(),
+            game_type: (!self.game_types.is_empty()).then(|| self.game_types.clone()),
+            venue_ids: (!self.venue_ids.is_empty()).then(|| self.venue_ids.clone()),
+            time_zone: self.time_zone.clone(),
+            hydrate: self.hydrate.as_ref().map(|h| h.to_string()),
+            fields,
+            ..Default::default()
+        }
+    }
+
+    // Applies the filters the API doesn't support to a response of this query
+    pub(crate) fn filter(&self, mut schedule: ScheduleResponse) -> ScheduleResponse {
+        if let Some(double_header) = &self.double_header {
+            for date in schedule.dates.iter_mut() {
+                date.games
+                    .retain(|game| &game.double_header == double_header);
+                date.total_games = date.games.len() as u32;
+            }
+            schedule.dates.retain(|date| !date.games.is_empty());
+            schedule.total_games = schedule.dates.iter().map(|d| d.total_games).sum();
+        }
+        schedule
+    }
+}
+
+impl Default for ScheduleQuery {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct ScheduleResponse {
     /// Number of games in response
     pub total_games: u32,
     /// Range of dates containing vector of games
     pub dates: Vec<Date>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
 impl ScheduleResponse {
@@ -24,14 +206,74 @@ impl ScheduleResponse {
     }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Date {
-    /// Date in YYYY-MM-DD format
-    pub date: String,
+    /// Official date of the games
+    #[serde(with = "date")]
+    pub date: NaiveDate,
     /// Total games on the date
     pub total_games: u32,
     /// List of games
     pub games: Vec<Game>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn schedule_query_params() {
+        let query = ScheduleQuery::new()
+            .teams(vec![140, 117])
+            .opponent(136)
+            .dates(
+                NaiveDate::from_ymd_opt(2023, 4, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2023, 4, 30).unwrap(),
+            )
+            .season(2023)
+            .game_types(vec![GameTypes::R, GameTypes::S])
+            .venues(vec![5325])
+            .time_zone("America/Chicago")
+            .fields(Fields::new(&["dates", "games", "gamePk"]))
+            .double_header(DoubleHeader::Split);
+        let params = serde_url_params::to_string(&query.params()).unwrap();
+        assert_eq!(
+            params,
+            "season=2023&fields=dates%2Cgames%2CgamePk%2CdoubleHeader&sportId=1&teamId=140\
+             &teamId=117&gameType=R&gameType=S&startDate=04%2F01%2F2023&endDate=04%2F30%2F2023\
+             &opponentId=136&venueIds=5325&timeZone=America%2FChicago"
+        );
+
+        let query = query.date(NaiveDate::from_ymd_opt(2023, 4, 12).unwrap());
+        let params = query.params();
+        assert_eq!(params.date.as_deref(), Some("04/12/2023"));
+        assert!(params.start_date.is_none());
+    }
+
+    #[test]
+    fn double_header_filter() {
+        let schedule: ScheduleResponse = serde_json::from_str(include_str!(
+            "../tests/fixtures/schedule_double_header.json"
+        ))
+        .unwrap();
+        assert_eq!(schedule.total_games, 3);
+
+        let split = ScheduleQuery::new()
+            .double_header(DoubleHeader::Split)
+            .filter(schedule.clone());
+        assert_eq!(split.total_games, 2);
+        assert!(split
+            .games()
+            .iter()
+            .all(|g| g.double_header == DoubleHeader::Split));
+        assert_eq!(split.games()[1].game_number, 2);
+
+        let unfiltered = ScheduleQuery::new().filter(schedule);
+        assert_eq!(unfiltered.total_games, 3);
+    }
+}
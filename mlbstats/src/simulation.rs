@@ -0,0 +1,604 @@
+use std::{cmp::Ordering, collections::HashMap};
+
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    clinching::RemainingGames,
+    playoffs::{HeadToHead, BYES, WILD_CARDS},
+    standings::{StandingsResponse, TeamRecord, PYTHAGOREAN_EXPONENT},
+};
+
+/// Model of the chance of the home team winning a game between two teams
+pub trait WinProbability {
+    /// Probability between 0 and 1 that the home team wins, values that aren't finite (e.g. NaN)
+    /// are treated as 0.5
+    fn home_win_probability(&self, home: &TeamRecord, away: &TeamRecord) -> f64;
+}
+
+impl<F> WinProbability for F
+where
+    F: Fn(&TeamRecord, &TeamRecord) -> f64,
+{
+    fn home_win_probability(&self, home: &TeamRecord, away: &TeamRecord) -> f64 {
+        self(home, away)
+    }
+}
+
+/// Log5 matchup between the Pythagorean winning percentages of two teams, computed from their
+/// runs scored and allowed, with a fixed edge for the home team
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub struct Log5 {
+    pub exponent: f64,
+    /// Added to the probability of the home team, MLB home teams win about 54% of their games
+    pub home_advantage: f64,
+}
+
+impl Default for Log5 {
+    fn default() -> Self {
+        Self {
+            exponent: PYTHAGOREAN_EXPONENT,
+            home_advantage: 0.04,
+        }
+    }
+}
+
+impl Log5 {
+    /// Expected winning percentage of a team from its run differential, .500 before any runs
+    pub fn strength(&self, team: &TeamRecord) -> f64 {
+        team.pythagorean_pct(self.exponent)
+    }
+}
+
+impl WinProbability for Log5 {
+    fn home_win_probability(&self, home: &TeamRecord, away: &TeamRecord) -> f64 {
+        let (a, b) = (self.strength(home), self.strength(away));
+        let log5 = match a + b - 2.0 * a * b {
+            0.0 => 0.5,
+            denominator => (a - a * b) / denominator,
+        };
+        (log5 + self.home_advantage).clamp(0.0, 1.0)
+    }
+}
+
+/// Chances of a team over the simulated seasons
+#[derive(PartialEq, Default, Debug, Clone)]
+pub struct TeamOdds {
+    pub team_id: u32,
+    pub league_id: u32,
+    pub division_id: u32,
+    pub average_wins: f64,
+    pub average_losses: f64,
+    pub division: f64,
+    pub wild_card: f64,
+    /// Chance of being one of the top two division winners
+    pub bye: f64,
+    pub pennant: f64,
+}
+
+impl TeamOdds {
+    /// Chance of making the postseason as a division winner or a wild card
+    pub fn playoffs(&self) -> f64 {
+        self.division + self.wild_card
+    }
+}
+
+#[derive(Default, Debug, Clone)]
+pub struct SimulationResults {
+    pub iterations: u32,
+    /// Teams in the order of the standings
+    pub teams: Vec<TeamOdds>,
+}
+
+impl SimulationResults {
+    pub fn team(&self, team_id: u32) -> Option<&TeamOdds> {
+        self.teams.iter().find(|team| team.team_id == team_id)
+    }
+}
+
+/// Monte Carlo simulation of the rest of a regular season and the postseason that follows it.
+/// Runs offline and gives the same results for the same seed, defaults to 10,000 seasons with
+/// the Log5 model.
+///
+/// Each game left is won by the home team with the probability given by the model. The field is
+/// then seeded as in PlayoffPicture, ties are broken by the head to head record between the tied
+/// teams, simulated games included, then at random. The postseason is played as best of 3 wild
+/// card series hosted by the higher seed, then best of 5 division series and best of 7
+/// championship series, which decides the pennant.
+#[derive(Debug, Clone)]
+pub struct Simulation<M = Log5> {
+    model: M,
+    iterations: u32,
+    seed: u64,
+}
+
+impl Simulation {
+    pub fn new() -> Self {
+        Self {
+            model: Log5::default(),
+            iterations: 10_000,
+            seed: 0,
+        }
+    }
+}
+
+impl Default for Simulation {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M: WinProbability> Simulation<M> {
+    /// Replaces the model used for every game, regular season and postseason
+    pub fn model<N: WinProbability>(self, model: N) -> Simulation<N> {
+        Simulation {
+            model,
+            iterations: self.iterations,
+            seed: self.seed,
+        }
+    }
+
+    /// Number of seasons simulated
+    pub fn iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Simulates the games left from regular season standings by division. Opponents missing from
+    /// the standings (e.g. interleague games with only one league's standings) are treated as
+    /// .500 teams and their results aren't tracked.
+    pub fn run(
+        &self,
+        standings: &StandingsResponse,
+        remaining: &RemainingGames,
+        head_to_head: &HeadToHead,
+    ) -> SimulationResults {
+        let teams: Vec<SimTeam> = standings
+            .records
+            .iter()
+            .flat_map(|division| {
+                division.team_records.iter().map(|record| SimTeam {
+                    record,
+                    league_id: division.league.id,
+                    division_id: division.division.id,
+                })
+            })
+            .collect();
+        let index: HashMap<u32, usize> = teams
+            .iter()
+            .enumerate()
+            .map(|(i, team)| (team.record.team.id, i))
+            .collect();
+
+        let unknown = TeamRecord::default();
+        let record = |id: u32| index.get(&id).map_or(&unknown, |&i| teams[i].record);
+        let games: Vec<Matchup> = remaining
+            .matchups()
+            .iter()
+            .map(|&(home, away)| Matchup {
+                home: index.get(&home).copied(),
+                away: index.get(&away).copied(),
+                home_ids: (home, away),
+                probability: self.probability(record(home), record(away)),
+            })
+            .collect();
+
+        let mut leagues: Vec<u32> = teams.iter().map(|team| team.league_id).collect();
+        leagues.sort_unstable();
+        leagues.dedup();
+        let mut divisions: Vec<(u32, u32)> = teams
+            .iter()
+            .map(|team| (team.league_id, team.division_id))
+            .collect();
+        divisions.sort_unstable();
+        divisions.dedup();
+
+        let mut tally = vec![Tally::default(); teams.len()];
+        let mut rng = ChaCha8Rng::seed_from_u64(self.seed);
+        for _ in 0..self.iterations {
+            let mut season = Season {
+                wins: teams.iter().map(|team| team.record.wins).collect(),
+                losses: teams.iter().map(|team| team.record.losses).collect(),
+                head_to_head: head_to_head.clone(),
+                tiebreak: (0..teams.len()).map(|_| rng.random()).collect(),
+            };
+            for game in &games {
+                let home_won = rng.random_bool(game.probability);
+                let (winner, loser) = if home_won {
+                    (game.home, game.away)
+                } else {
+                    (game.away, game.home)
+                };
+                if let Some(winner) = winner {
+                    season.wins[winner] += 1;
+                }
+                if let Some(loser) = loser {
+                    season.losses[loser] += 1;
+                }
+                let (home_id, away_id) = game.home_ids;
+                if home_won {
+                    season.head_to_head.add_game(home_id, away_id);
+                } else {
+                    season.head_to_head.add_game(away_id, home_id);
+                }
+            }
+
+            for (i, t) in tally.iter_mut().enumerate() {
+                t.wins += season.wins[i] as u64;
+                t.losses += season.losses[i] as u64;
+            }
+
+            for &league_id in &leagues {
+                let mut winners = vec![];
+                let mut others = vec![];
+                for &(_, division_id) in divisions.iter().filter(|(l, _)| *l == league_id) {
+                    let members: Vec<usize> = (0..teams.len())
+                        .filter(|&i| {
+                            teams[i].league_id == league_id && teams[i].division_id == division_id
+                        })
+                        .collect();
+                    let mut ranked = season.rank(&teams, members).into_iter();
+                    winners.extend(ranked.next());
+                    others.extend(ranked);
+                }
+
+                let mut seeds = season.rank(&teams, winners);
+                for (seed, &i) in seeds.iter().enumerate() {
+                    tally[i].division += 1;
+                    if seed < BYES {
+                        tally[i].bye += 1;
+                    }
+                }
+                for &i in season.rank(&teams, others).iter().take(WILD_CARDS) {
+                    tally[i].wild_card += 1;
+                    seeds.push(i);
+                }
+
+                if let Some(pennant) = self.postseason(&teams, &seeds, &mut rng) {
+                    tally[pennant].pennant += 1;
+                }
+            }
+        }
+
+        let iterations = self.iterations.max(1) as f64;
+        SimulationResults {
+            iterations: self.iterations,
+            teams: teams
+                .iter()
+                .zip(tally)
+                .map(|(team, tally)| TeamOdds {
+                    team_id: team.record.team.id,
+                    league_id: team.league_id,
+                    division_id: team.division_id,
+                    average_wins: tally.wins as f64 / iterations,
+                    average_losses: tally.losses as f64 / iterations,
+                    division: tally.division as f64 / iterations,
+                    wild_card: tally.wild_card as f64 / iterations,
+                    bye: tally.bye as f64 / iterations,
+                    pennant: tally.pennant as f64 / iterations,
+                })
+                .collect(),
+        }
+    }
+
+    // Plays out the bracket of a league from its seeds, None without a full field
+    // Probability from the model clamped between 0 and 1, a coin flip when the model doesn't
+    // return a number (e.g. NaN)
+    fn probability(&self, home: &TeamRecord, away: &TeamRecord) -> f64 {
+        let probability = self.model.home_win_probability(home, away);
+        if probability.is_finite() {
+            probability.clamp(0.0, 1.0)
+        } else {
+            0.5
+        }
+    }
+
+    fn postseason(
+        &self,
+        teams: &[SimTeam],
+        seeds: &[usize],
+        rng: &mut ChaCha8Rng,
+    ) -> Option<usize> {
+        let &[one, two, three, four, five, six] = seeds else {
+            return None;
+        };
+        let series = |higher: usize, lower: usize, format: &[bool], rng: &mut ChaCha8Rng| {
+            self.series(teams, higher, lower, format, rng)
+        };
+        let wild_card_one = series(four, five, &WILD_CARD_SERIES, rng);
+        let wild_card_two = series(three, six, &WILD_CARD_SERIES, rng);
+        let division_one = series(one, wild_card_one, &DIVISION_SERIES, rng);
+        let division_two = series(two, wild_card_two, &DIVISION_SERIES, rng);
+        let (higher, lower) = if seeds.iter().position(|&s| s == division_one)
+            < seeds.iter().position(|&s| s == division_two)
+        {
+            (division_one, division_two)
+        } else {
+            (division_two, division_one)
+        };
+        Some(series(higher, lower, &CHAMPIONSHIP_SERIES, rng))
+    }
+
+    // Plays a series until a team wins a majority of the games in the format
+    fn series(
+        &self,
+        teams: &[SimTeam],
+        higher: usize,
+        lower: usize,
+        format: &[bool],
+        rng: &mut ChaCha8Rng,
+    ) -> usize {
+        let needed = format.len() / 2 + 1;
+        let (mut higher_wins, mut lower_wins) = (0, 0);
+        for &higher_home in format {
+            let (home, away) = if higher_home {
+                (higher, lower)
+            } else {
+                (lower, higher)
+            };
+            let probability = self.probability(teams[home].record, teams[away].record);
+            if rng.random_bool(probability) == higher_home {
+                higher_wins += 1;
+            } else {
+                lower_wins += 1;
+            }
+            if higher_wins == needed || lower_wins == needed {
+                break;
+            }
+        }
+        if higher_wins > lower_wins {
+            higher
+        } else {
+            lower
+        }
+    }
+}
+
+// Whether the higher seed is at home for each game of a series
+const WILD_CARD_SERIES: [bool; 3] = [true, true, true];
+const DIVISION_SERIES: [bool; 5] = [true, true, false, false, true];
+const CHAMPIONSHIP_SERIES: [bool; 7] = [true, true, false, false, false, true, true];
+
+struct SimTeam<'a> {
+    record: &'a TeamRecord,
+    league_id: u32,
+    division_id: u32,
+}
+
+struct Matchup {
+    // indices into the simulated teams, None for teams missing from the standings
+    home: Option<usize>,
+    away: Option<usize>,
+    home_ids: (u32, u32),
+    probability: f64,
+}
+
+#[derive(Default, Clone)]
+struct Tally {
+    wins: u64,
+    losses: u64,
+    division: u32,
+    wild_card: u32,
+    bye: u32,
+    pennant: u32,
+}
+
+struct Season {
+    wins: Vec<u32>,
+    losses: Vec<u32>,
+    head_to_head: HeadToHead,
+    // random key breaking ties the head to head records don't
+    tiebreak: Vec<u64>,
+}
+
+impl Season {
+    fn pct(&self, i: usize) -> f64 {
+        match self.wins[i] + self.losses[i] {
+            0 => 0.0,
+            games => self.wins[i] as f64 / games as f64,
+        }
+    }
+
+    // Orders teams by winning percentage, breaking ties by the head to head record against the
+    // rest of the tied teams
+    fn rank(&self, teams: &[SimTeam], mut entries: Vec<usize>) -> Vec<usize> {
+        entries.sort_by(|&a, &b| descending(self.pct(a), self.pct(b)));
+
+        let mut ranked = Vec::with_capacity(entries.len());
+        for tied in entries.chunk_by(|&a, &b| self.pct(a) == self.pct(b)) {
+            let mut tied = tied.to_vec();
+            if tied.len() > 1 {
+                let h2h: HashMap<usize, f64> = tied
+                    .iter()
+                    .map(|&i| {
+                        let (wins, losses) = tied
+                            .iter()
+                            .filter(|&&opponent| opponent != i)
+                            .map(|&opponent| {
+                                self.head_to_head
+                                    .record(teams[i].record.team.id, teams[opponent].record.team.id)
+                            })
+                            .fold((0, 0), |(w, l), (wins, losses)| (w + wins, l + losses));
+                        let pct = match wins + losses {
+                            0 => 0.5,
+                            games => wins as f64 / games as f64,
+                        };
+                        (i, pct)
+                    })
+                    .collect();
+                tied.sort_by(|a, b| {
+                    descending(h2h[a], h2h[b])
+                        .then_with(|| self.tiebreak[*a].cmp(&self.tiebreak[*b]))
+                });
+            }
+            ranked.extend(tied);
+        }
+        ranked
+    }
+}
+
+// Higher values sort first
+fn descending(a: f64, b: f64) -> Ordering {
+    b.partial_cmp(&a).unwrap_or(Ordering::Equal)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn standings() -> StandingsResponse {
+        serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap()
+    }
+
+    fn series(winner: u32, loser: u32, wins: u32, losses: u32) -> HeadToHead {
+        let mut head_to_head = HeadToHead::default();
+        (0..wins).for_each(|_| head_to_head.add_game(winner, loser));
+        (0..losses).for_each(|_| head_to_head.add_game(loser, winner));
+        head_to_head
+    }
+
+    fn close(a: f64, b: f64) -> bool {
+        (a - b).abs() < 1e-9
+    }
+
+    #[test]
+    fn log5() {
+        let model = Log5::default();
+        let even = TeamRecord {
+            runs_scored: 700,
+            runs_allowed: 700,
+            ..Default::default()
+        };
+        let strong = TeamRecord {
+            runs_scored: 800,
+            runs_allowed: 600,
+            ..Default::default()
+        };
+        assert!(close(model.strength(&even), 0.5));
+        assert!(close(model.home_win_probability(&even, &even), 0.54));
+        assert!(close(
+            model.home_win_probability(&strong, &even),
+            model.strength(&strong) + 0.04
+        ));
+        assert!(model.home_win_probability(&even, &strong) < 0.5);
+        assert!(close(model.strength(&TeamRecord::default()), 0.5));
+    }
+
+    #[test]
+    fn final_standings() {
+        // with no games left only the postseason is simulated
+        let results = Simulation::new().iterations(1_000).run(
+            &standings(),
+            &RemainingGames::default(),
+            &series(117, 140, 9, 4),
+        );
+        assert_eq!(results.teams.len(), 15);
+
+        let odds = |id| results.team(id).unwrap();
+        assert!(close(odds(110).division, 1.0));
+        assert!(close(odds(110).bye, 1.0));
+        assert!(close(odds(117).division, 1.0));
+        assert!(close(odds(140).wild_card, 1.0));
+        assert!(close(odds(141).playoffs(), 1.0));
+        assert!(close(odds(142).bye, 0.0));
+        assert!(close(odds(136).playoffs(), 0.0));
+        assert!(close(odds(136).pennant, 0.0));
+        assert!(close(odds(110).average_wins, 101.0));
+
+        let pennants: f64 = results.teams.iter().map(|team| team.pennant).sum();
+        assert!(close(pennants, 1.0));
+        assert!(odds(110).pennant > 0.0 && odds(139).pennant > 0.0);
+    }
+
+    #[test]
+    fn deterministic_for_a_seed() {
+        let standings = standings();
+        let mut remaining = RemainingGames::default();
+        for _ in 0..3 {
+            remaining.add_game(136, 117);
+            remaining.add_game(140, 136);
+        }
+        let head_to_head = HeadToHead::default();
+        let run = |seed| {
+            Simulation::new()
+                .iterations(500)
+                .seed(seed)
+                .run(&standings, &remaining, &head_to_head)
+        };
+
+        assert_eq!(run(7).teams, run(7).teams);
+        assert_ne!(run(7).teams, run(8).teams);
+
+        let results = run(7);
+        for team in &results.teams {
+            assert!((0.0..=1.0).contains(&team.playoffs()));
+            assert!(team.bye <= team.division);
+        }
+        let league: Vec<&TeamOdds> = results
+            .teams
+            .iter()
+            .filter(|t| t.league_id == 103)
+            .collect();
+        assert!(close(league.iter().map(|t| t.division).sum(), 3.0));
+        assert!(close(league.iter().map(|t| t.wild_card).sum(), 3.0));
+        assert!(close(league.iter().map(|t| t.pennant).sum(), 1.0));
+        // SEA (88-74) needs a sweep of HOU and TEX to pass them
+        let mariners = results.team(136).unwrap();
+        assert!(mariners.division > 0.0 && mariners.division < 0.5);
+        assert!(close(
+            mariners.average_wins + mariners.average_losses,
+            168.0
+        ));
+    }
+
+    #[test]
+    fn custom_model() {
+        // the home team always wins: SEA sweeps HOU and TEX at home to win the West at 94-74
+        let mut remaining = RemainingGames::default();
+        for _ in 0..3 {
+            remaining.add_game(136, 117);
+            remaining.add_game(136, 140);
+        }
+        let results = Simulation::new()
+            .model(|_: &TeamRecord, _: &TeamRecord| 1.0)
+            .iterations(100)
+            .run(&standings(), &remaining, &HeadToHead::default());
+
+        let mariners = results.team(136).unwrap();
+        assert!(close(mariners.division, 1.0));
+        assert!(close(mariners.average_wins, 94.0));
+        // HOU and TEX drop to 90-75 behind TOR (89-73) and split the last wild card at random
+        assert!(close(results.team(141).unwrap().wild_card, 1.0));
+        let (astros, rangers) = (results.team(117).unwrap(), results.team(140).unwrap());
+        assert!(close(astros.wild_card + rangers.wild_card, 1.0));
+        assert!(astros.wild_card > 0.0 && rangers.wild_card > 0.0);
+        // the higher seed wins every series when the home team always wins
+        assert!(close(results.team(110).unwrap().pennant, 1.0));
+    }
+
+    #[test]
+    fn non_finite_probabilities() {
+        // a model returning NaN plays every game as a coin flip instead of panicking
+        let mut remaining = RemainingGames::default();
+        for _ in 0..3 {
+            remaining.add_game(136, 117);
+            remaining.add_game(140, 136);
+        }
+        let run = |model: fn(&TeamRecord, &TeamRecord) -> f64| {
+            Simulation::new().model(model).iterations(200).run(
+                &standings(),
+                &remaining,
+                &HeadToHead::default(),
+            )
+        };
+        let results = run(|_, _| f64::NAN);
+        assert_eq!(results.teams, run(|_, _| 0.5).teams);
+        assert_eq!(results.teams, run(|_, _| f64::INFINITY).teams);
+        assert!(close(results.teams.iter().map(|t| t.pennant).sum(), 1.0));
+    }
+}
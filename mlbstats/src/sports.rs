@@ -0,0 +1,160 @@
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::MLBStatsError;
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SportsResponse {
+    pub sports: Vec<SportInfo>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct SportInfo {
+    /// Unique id for the sport, used as the `sportId` query param
+    pub id: u32,
+
+    /// Short code of the sport (e.g. aaa)
+    pub code: String,
+
+    /// Path to the sport endpoint
+    pub link: String,
+
+    /// Full name of the sport (e.g. Triple-A)
+    pub name: String,
+
+    /// Abbreviation of the sport (e.g. AAA)
+    pub abbreviation: String,
+
+    /// Order the sport is displayed in on MLB properties
+    pub sort_order: u32,
+
+    /// Whether the sport is currently active
+    pub active_status: bool,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Levels of play available through the statsapi. The id is used as the `sportId` param
+/// on most endpoints.
+#[derive(PartialEq, Debug, Clone)]
+pub enum Sport {
+    /// Major League Baseball
+    MLB,
+    /// Triple-A
+    AAA,
+    /// Double-A
+    AA,
+    /// High-A
+    HighA,
+    /// Single-A
+    SingleA,
+    /// Rookie
+    Rookie,
+    /// Winter Leagues
+    Winter,
+    /// Independent Leagues
+    Independent,
+}
+
+impl Sport {
+    /// Returns the identifier of the sport
+    pub fn id(&self) -> u32 {
+        match self {
+            Sport::MLB => 1,
+            Sport::AAA => 11,
+            Sport::AA => 12,
+            Sport::HighA => 13,
+            Sport::SingleA => 14,
+            Sport::Rookie => 16,
+            Sport::Winter => 17,
+            Sport::Independent => 23,
+        }
+    }
+}
+
+impl FromStr for Sport {
+    type Err = MLBStatsError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.to_lowercase().as_str() {
+            "mlb" => Sport::MLB,
+            "aaa" => Sport::AAA,
+            "aa" | "aax" => Sport::AA,
+            "high-a" | "higha" | "afa" => Sport::HighA,
+            "single-a" | "singlea" | "a" | "afx" => Sport::SingleA,
+            "rookie" | "rok" => Sport::Rookie,
+            "winter" | "win" => Sport::Winter,
+            "independent" | "ind" => Sport::Independent,
+            _ => return Err(MLBStatsError::MLBConversionError("sport".into(), s.into())),
+        })
+    }
+}
+
+impl TryFrom<u32> for Sport {
+    type Error = MLBStatsError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        Ok(match value {
+            1 => Sport::MLB,
+            11 => Sport::AAA,
+            12 => Sport::AA,
+            13 => Sport::HighA,
+            14 => Sport::SingleA,
+            16 => Sport::Rookie,
+            17 => Sport::Winter,
+            23 => Sport::Independent,
+            _ => {
+                return Err(MLBStatsError::MLBConversionError(
+                    "sport".into(),
+                    value.to_string(),
+                ))
+            }
+        })
+    }
+}
+
+impl From<Sport> for u32 {
+    fn from(value: Sport) -> Self {
+        value.id()
+    }
+}
+
+impl Serialize for Sport {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_u32(self.id())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use serde_test::{assert_ser_tokens, Token};
+
+    #[test]
+    fn sport_conversions() {
+        assert_eq!(Sport::try_from(11).unwrap(), Sport::AAA);
+        assert!(Sport::try_from(2).is_err());
+        assert_eq!(Sport::from_str("AAA").unwrap(), Sport::AAA);
+        assert_eq!(Sport::from_str("afa").unwrap(), Sport::HighA);
+
+        let rookie: u32 = Sport::Rookie.into();
+        assert_eq!(rookie, 16);
+    }
+
+    #[test]
+    fn serialize_sport() {
+        assert_ser_tokens(&Sport::AA, &[Token::U32(12)]);
+    }
+}
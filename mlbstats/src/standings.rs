@@ -1,36 +1,71 @@
+use std::fmt::Display;
+
 use crate::{
+    clinching::{clinch_numbers, ClinchNumbers, RemainingGames},
+    playoffs::{HeadToHead, PlayoffPicture},
     teams::Team,
-    types::{IdNameLink, Record},
+    types::{optional_number, IdNameLink, Pct, Record},
 };
-use serde::Deserialize;
+use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct StandingsResponse {
     pub records: Vec<StandingsRecord>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl StandingsResponse {
+    /// Postseason field of a league if the season ended today, see PlayoffPicture. Requires the
+    /// regular season standings of every division in the league.
+    pub fn playoff_picture(&self, league_id: u32, head_to_head: &HeadToHead) -> PlayoffPicture {
+        PlayoffPicture::new(self, league_id, head_to_head)
+    }
+
+    /// Magic and elimination numbers of every team, see clinching::clinch_numbers
+    pub fn clinch_numbers(
+        &self,
+        remaining: &RemainingGames,
+        head_to_head: &HeadToHead,
+    ) -> Vec<ClinchNumbers> {
+        clinch_numbers(self, remaining, head_to_head)
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct StandingsRecord {
     pub standings_type: String,
     pub league: IdNameLink,
     pub division: IdNameLink,
     pub team_records: Vec<TeamRecord>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct TeamRecord {
     pub team: Team,
     pub streak: Streak,
-    pub division_rank: String,
-    pub league_rank: String,
-    pub wild_card_rank: Option<String>,
-    pub sport_rank: String,
+    #[serde(with = "optional_number")]
+    pub division_rank: Option<u32>,
+    #[serde(with = "optional_number")]
+    pub league_rank: Option<u32>,
+    /// Rank among the teams competing for a wild card, None for division leaders
+    #[serde(with = "optional_number")]
+    pub wild_card_rank: Option<u32>,
+    #[serde(with = "optional_number")]
+    pub sport_rank: Option<u32>,
     pub games_played: u32,
-    pub wild_card_games_back: String,
-    pub division_games_back: String,
+    pub wild_card_games_back: GamesBack,
+    pub division_games_back: GamesBack,
+    pub league_games_back: GamesBack,
     pub league_record: Record,
     pub records: Split,
     pub runs_allowed: u32,
@@ -38,19 +73,282 @@ pub struct TeamRecord {
     pub wins: u32,
     pub losses: u32,
     pub run_differential: i32,
-    pub winning_percentage: String,
+    pub winning_percentage: Pct,
+    /// Whether the team has clinched a playoff spot
+    pub clinched: bool,
+    /// Code of what the team has clinched (e.g. y), see TeamRecord::clinch
+    pub clinch_indicator: Option<String>,
+    pub division_champ: bool,
+    pub division_leader: bool,
+    /// Wins by the team or losses by the second place team needed to clinch the division, only
+    /// returned for division leaders
+    pub magic_number: Option<ClinchNumber>,
+    /// Losses by the team or wins by the division leader that eliminate the team from the
+    /// division
+    pub elimination_number: Option<ClinchNumber>,
+    pub wild_card_elimination_number: Option<ClinchNumber>,
     pub does_not_exist: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl TeamRecord {
+    /// What the team has clinched, None if it hasn't clinched anything
+    pub fn clinch(&self) -> Option<ClinchIndicator> {
+        self.clinch_indicator
+            .as_deref()
+            .and_then(ClinchIndicator::from_code)
+    }
+
+    /// Whether the team has been eliminated from both the division and the wild card
+    pub fn eliminated(&self) -> bool {
+        self.elimination_number == Some(ClinchNumber::Eliminated)
+            && self.wild_card_elimination_number == Some(ClinchNumber::Eliminated)
+    }
+
+    /// Winning percentage expected from runs scored and allowed with a fixed exponent, .500
+    /// before any runs
+    pub fn pythagorean_pct(&self, exponent: f64) -> f64 {
+        let scored = (self.runs_scored as f64).powf(exponent);
+        let allowed = (self.runs_allowed as f64).powf(exponent);
+        match scored + allowed {
+            0.0 => 0.5,
+            total => scored / total,
+        }
+    }
+
+    /// Exponent adjusted to the run environment, ((RS + RA) / G) ^ 0.287
+    pub fn pythagenpat_exponent(&self) -> f64 {
+        match self.wins + self.losses {
+            0 => PYTHAGOREAN_EXPONENT,
+            games => ((self.runs_scored + self.runs_allowed) as f64 / games as f64)
+                .powf(PYTHAGENPAT_POWER),
+        }
+    }
+
+    pub fn expected_pct(&self, expectation: Expectation) -> f64 {
+        match expectation {
+            Expectation::Pythagorean => self.pythagorean_pct(PYTHAGOREAN_EXPONENT),
+            Expectation::PythagenPat => self.pythagorean_pct(self.pythagenpat_exponent()),
+        }
+    }
+
+    /// Wins expected over the games played
+    pub fn expected_wins(&self, expectation: Expectation) -> f64 {
+        self.expected_pct(expectation) * (self.wins + self.losses) as f64
+    }
+
+    pub fn expected_losses(&self, expectation: Expectation) -> f64 {
+        (self.wins + self.losses) as f64 - self.expected_wins(expectation)
+    }
+
+    /// Actual wins minus expected wins, positive for teams outperforming their run differential
+    pub fn luck(&self, expectation: Expectation) -> f64 {
+        self.wins as f64 - self.expected_wins(expectation)
+    }
+
+    /// Expected wins minus the wins of the expected record returned by the API, None when the
+    /// standings don't include expected records
+    pub fn expected_wins_vs_api(&self, expectation: Expectation) -> Option<f64> {
+        self.records
+            .expected()
+            .map(|record| self.expected_wins(expectation) - record.wins as f64)
+    }
+}
+
+/// Exponent of the Pythagorean expectation, fit to MLB run scoring
+pub const PYTHAGOREAN_EXPONENT: f64 = 1.83;
+/// Power applied to runs per game to get the PythagenPat exponent
+pub const PYTHAGENPAT_POWER: f64 = 0.287;
+
+/// Formula turning runs scored and allowed into an expected winning percentage
+#[derive(PartialEq, Eq, Default, Debug, Clone, Copy)]
+pub enum Expectation {
+    /// RS^x / (RS^x + RA^x) with x = 1.83
+    Pythagorean,
+    /// Pythagorean with the exponent derived from runs per game, more accurate in extreme run
+    /// environments
+    #[default]
+    PythagenPat,
+}
+
+/// Games behind the leader, sent by the API as a string. The leader's "-" is 0 and teams ahead
+/// of the wild card cut line (e.g. +1.5) are negative.
+#[derive(PartialEq, PartialOrd, Default, Debug, Clone, Copy)]
+pub enum GamesBack {
+    /// Not sent, e.g. the wild card games back of a division leader
+    #[default]
+    NotApplicable,
+    Games(f64),
+    /// Sent as "E", eliminated from the race
+    Eliminated,
+}
+
+impl GamesBack {
+    /// Games behind, None when not applicable or eliminated
+    pub fn games(&self) -> Option<f64> {
+        match self {
+            GamesBack::Games(games) => Some(*games),
+            _ => None,
+        }
+    }
+
+    pub fn is_leader(&self) -> bool {
+        *self == GamesBack::Games(0.0)
+    }
+
+    /// Whether the team is ahead of the cut line, only possible for wild card games back
+    pub fn is_ahead(&self) -> bool {
+        self.games().is_some_and(|games| games < 0.0)
+    }
+
+    pub fn is_eliminated(&self) -> bool {
+        *self == GamesBack::Eliminated
+    }
+}
+
+impl Display for GamesBack {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match *self {
+            GamesBack::NotApplicable => Ok(()),
+            GamesBack::Games(0.0) => write!(f, "-"),
+            GamesBack::Games(games) if games < 0.0 => write!(f, "+{:.1}", -games),
+            GamesBack::Games(games) => write!(f, "{:.1}", games),
+            GamesBack::Eliminated => write!(f, "E"),
+        }
+    }
+}
+
+impl Serialize for GamesBack {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GamesBack::NotApplicable => serializer.serialize_none(),
+            _ => serializer.serialize_str(&self.to_string()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GamesBack {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let Some(games) = Option::<String>::deserialize(deserializer)? else {
+            return Ok(GamesBack::NotApplicable);
+        };
+        let parsed = match games.as_str() {
+            "-" => Ok(0.0),
+            "E" => return Ok(GamesBack::Eliminated),
+            _ => match games.strip_prefix('+') {
+                Some(ahead) => ahead.parse::<f64>().map(|games| -games),
+                None => games.parse(),
+            },
+        };
+        parsed
+            .map(GamesBack::Games)
+            .map_err(|_| Error::custom(format!("invalid games back {:?}", games)))
+    }
+}
+
+/// Magic or elimination number, sent by the API as a string
+#[derive(PartialEq, Eq, Default, Debug, Clone, Copy)]
+pub enum ClinchNumber {
+    /// Sent as "-", e.g. the magic number of a team that has already clinched
+    #[default]
+    NotApplicable,
+    /// Sent as "E"
+    Eliminated,
+    Remaining(u32),
+}
+
+impl Display for ClinchNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClinchNumber::NotApplicable => write!(f, "-"),
+            ClinchNumber::Eliminated => write!(f, "E"),
+            ClinchNumber::Remaining(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+impl Serialize for ClinchNumber {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for ClinchNumber {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let number = String::deserialize(deserializer)?;
+        match number.as_str() {
+            "-" => Ok(ClinchNumber::NotApplicable),
+            "E" => Ok(ClinchNumber::Eliminated),
+            _ => number
+                .parse()
+                .map(ClinchNumber::Remaining)
+                .map_err(|_| Error::custom(format!("invalid clinch number {:?}", number))),
+        }
+    }
+}
+
+/// What a team has clinched, from the clinch indicator shown next to the team in standings
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ClinchIndicator {
+    /// z: best record in the league and a first round bye
+    Bye,
+    /// y: division title
+    Division,
+    /// x: playoff spot
+    Playoffs,
+    /// w: wild card
+    WildCard,
+}
+
+impl ClinchIndicator {
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code {
+            "z" => Some(ClinchIndicator::Bye),
+            "y" => Some(ClinchIndicator::Division),
+            "x" => Some(ClinchIndicator::Playoffs),
+            "w" => Some(ClinchIndicator::WildCard),
+            _ => None,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            ClinchIndicator::Bye => "z",
+            ClinchIndicator::Division => "y",
+            ClinchIndicator::Playoffs => "x",
+            ClinchIndicator::WildCard => "w",
+        }
+    }
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Streak {
     pub streak_type: String,
     pub streak_number: u32,
     pub streak_code: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Split {
     pub split_records: Vec<Record>,
@@ -58,9 +356,148 @@ pub struct Split {
     pub overall_records: Vec<Record>,
     pub league_records: Vec<Record>,
     pub expected_records: Vec<Record>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+impl Split {
+    /// Record of a split, None when the split wasn't returned
+    pub fn split(&self, split: SplitType) -> Option<&Record> {
+        self.split_records
+            .iter()
+            .find(|record| record.kind.as_deref() == Some(split.kind()))
+    }
+
+    pub fn home(&self) -> Option<&Record> {
+        self.split(SplitType::Home)
+    }
+
+    pub fn away(&self) -> Option<&Record> {
+        self.split(SplitType::Away)
+    }
+
+    pub fn last_ten(&self) -> Option<&Record> {
+        self.split(SplitType::LastTen)
+    }
+
+    pub fn extra_innings(&self) -> Option<&Record> {
+        self.split(SplitType::ExtraInning)
+    }
+
+    pub fn one_run(&self) -> Option<&Record> {
+        self.split(SplitType::OneRun)
+    }
+
+    pub fn day(&self) -> Option<&Record> {
+        self.split(SplitType::Day)
+    }
+
+    pub fn night(&self) -> Option<&Record> {
+        self.split(SplitType::Night)
+    }
+
+    pub fn grass(&self) -> Option<&Record> {
+        self.split(SplitType::Grass)
+    }
+
+    pub fn turf(&self) -> Option<&Record> {
+        self.split(SplitType::Turf)
+    }
+
+    /// Record in games started by a left handed pitcher
+    pub fn vs_left(&self) -> Option<&Record> {
+        self.split(SplitType::Left)
+    }
+
+    /// Record in games started by a right handed pitcher
+    pub fn vs_right(&self) -> Option<&Record> {
+        self.split(SplitType::Right)
+    }
+
+    /// Record against teams with a winning record
+    pub fn vs_winners(&self) -> Option<&Record> {
+        self.split(SplitType::Winners)
+    }
+
+    /// Record expected from runs scored and allowed as computed by the API (xWinLoss)
+    pub fn expected(&self) -> Option<&Record> {
+        self.expected_record("xWinLoss")
+    }
+
+    /// Expected record projected over the full season (xWinLossSeason)
+    pub fn expected_season(&self) -> Option<&Record> {
+        self.expected_record("xWinLossSeason")
+    }
+
+    fn expected_record(&self, kind: &str) -> Option<&Record> {
+        self.expected_records
+            .iter()
+            .find(|record| record.kind.as_deref() == Some(kind))
+    }
+
+    /// Record against the teams of a division, MLBDivision converts with `.id()`
+    pub fn vs_division(&self, division_id: u32) -> Option<&Record> {
+        self.division_records.iter().find(|record| {
+            record
+                .division
+                .as_ref()
+                .is_some_and(|d| d.id == division_id)
+        })
+    }
+}
+
+/// Split records returned with standings, identified by the type of the record
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SplitType {
+    Home,
+    Away,
+    /// Games started by a left handed pitcher
+    Left,
+    LeftHome,
+    LeftAway,
+    /// Games started by a right handed pitcher
+    Right,
+    RightHome,
+    RightAway,
+    LastTen,
+    ExtraInning,
+    OneRun,
+    /// Games against teams with a winning record
+    Winners,
+    Day,
+    Night,
+    Grass,
+    Turf,
+}
+
+impl SplitType {
+    /// Type of the record as returned by the API (e.g. lastTen)
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SplitType::Home => "home",
+            SplitType::Away => "away",
+            SplitType::Left => "left",
+            SplitType::LeftHome => "leftHome",
+            SplitType::LeftAway => "leftAway",
+            SplitType::Right => "right",
+            SplitType::RightHome => "rightHome",
+            SplitType::RightAway => "rightAway",
+            SplitType::LastTen => "lastTen",
+            SplitType::ExtraInning => "extraInning",
+            SplitType::OneRun => "oneRun",
+            SplitType::Winners => "winners",
+            SplitType::Day => "day",
+            SplitType::Night => "night",
+            SplitType::Grass => "grass",
+            SplitType::Turf => "turf",
+        }
+    }
+}
+
+/// Standings available through the standingsTypes param
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum StandingType {
     RegularSeason,
@@ -76,3 +513,131 @@ pub enum StandingType {
     ByLeague,
     ByOrganization,
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::params::QueryParams;
+
+    fn team(standings: &StandingsResponse, id: u32) -> &TeamRecord {
+        standings
+            .records
+            .iter()
+            .flat_map(|r| r.team_records.iter())
+            .find(|t| t.team.id == id)
+            .unwrap()
+    }
+
+    #[test]
+    fn typed_standings() {
+        let standings: StandingsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap();
+
+        let orioles = team(&standings, 110);
+        assert_eq!(orioles.division_rank, Some(1));
+        assert_eq!(orioles.wild_card_rank, None);
+        assert!(orioles.division_games_back.is_leader());
+        assert_eq!(orioles.winning_percentage.value(), Some(0.623));
+        assert_eq!(orioles.winning_percentage.to_string(), ".623");
+        assert_eq!(orioles.magic_number, Some(ClinchNumber::NotApplicable));
+        assert_eq!(orioles.clinch(), Some(ClinchIndicator::Bye));
+
+        let rays = team(&standings, 139);
+        assert_eq!(rays.division_games_back.games(), Some(2.0));
+        assert_eq!(rays.wild_card_games_back.games(), Some(-10.0));
+        assert!(rays.wild_card_games_back.is_ahead());
+        assert_eq!(rays.wild_card_games_back.to_string(), "+10.0");
+        assert_eq!(rays.clinch(), Some(ClinchIndicator::WildCard));
+        assert!(rays.clinched);
+
+        let mariners = team(&standings, 136);
+        assert_eq!(mariners.wild_card_rank, Some(4));
+        assert_eq!(mariners.elimination_number, Some(ClinchNumber::Eliminated));
+        assert!(mariners.eliminated());
+        assert_eq!(mariners.clinch(), None);
+        assert!(!team(&standings, 140).eliminated());
+    }
+
+    #[test]
+    fn split_records() {
+        let standings: StandingsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap();
+        let splits = &team(&standings, 110).records;
+
+        let record = |record: Option<&Record>| record.map(|r| (r.wins, r.losses));
+        assert_eq!(record(splits.home()), Some((50, 31)));
+        assert_eq!(record(splits.away()), Some((51, 30)));
+        assert_eq!(record(splits.last_ten()), Some((6, 4)));
+        assert_eq!(record(splits.extra_innings()), Some((9, 5)));
+        assert_eq!(record(splits.one_run()), Some((27, 17)));
+        assert_eq!(record(splits.vs_left()), Some((26, 16)));
+        assert_eq!(record(splits.vs_right()), Some((75, 45)));
+        assert_eq!(record(splits.turf()), Some((7, 5)));
+        assert_eq!(record(splits.split(SplitType::LeftAway)), Some((13, 8)));
+        assert_eq!(record(splits.vs_division(201)), Some((32, 20)));
+        assert_eq!(record(splits.vs_division(203)), None);
+        assert_eq!(record(Split::default().home()), None);
+    }
+
+    #[test]
+    fn expected_records() {
+        let standings: StandingsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/standings_al_2023.json")).unwrap();
+
+        // BAL outscored opponents 807-678 on the way to 101-61, the API expects 94-68
+        let orioles = team(&standings, 110);
+        let expected = orioles.records.expected().unwrap();
+        assert_eq!((expected.wins, expected.losses), (94, 68));
+        assert!((orioles.expected_pct(Expectation::Pythagorean) - 0.579).abs() < 0.001);
+        assert!((orioles.pythagenpat_exponent() - 1.889).abs() < 0.001);
+        assert!((orioles.expected_wins(Expectation::PythagenPat) - 94.2).abs() < 0.1);
+        assert!((orioles.expected_losses(Expectation::PythagenPat) - 67.8).abs() < 0.1);
+        assert!((orioles.luck(Expectation::PythagenPat) - 6.8).abs() < 0.1);
+        for expectation in [Expectation::Pythagorean, Expectation::PythagenPat] {
+            assert!(orioles.expected_wins_vs_api(expectation).unwrap().abs() < 0.5);
+        }
+
+        let empty = TeamRecord::default();
+        assert_eq!(empty.expected_pct(Expectation::PythagenPat), 0.5);
+        assert_eq!(empty.luck(Expectation::Pythagorean), 0.0);
+        assert_eq!(empty.expected_wins_vs_api(Expectation::Pythagorean), None);
+    }
+
+    #[test]
+    fn standing_type_params() {
+        let params = QueryParams {
+            standings_types: Some(vec![StandingType::WildCard, StandingType::FirstHalf]),
+            ..Default::default()
+        };
+        assert_eq!(
+            serde_url_params::to_string(&params).unwrap(),
+            "standingsTypes=wildCard&standingsTypes=firstHalf&sportId=1"
+        );
+    }
+
+    #[test]
+    fn games_back_and_pct() {
+        let parse = |games: &str| serde_json::from_str::<GamesBack>(games).unwrap();
+        assert_eq!(parse("\"-\""), GamesBack::Games(0.0));
+        assert_eq!(parse("\"+1.5\""), GamesBack::Games(-1.5));
+        assert_eq!(parse("\"12.5\""), GamesBack::Games(12.5));
+        assert_eq!(parse("\"E\""), GamesBack::Eliminated);
+        assert_eq!(parse("null"), GamesBack::NotApplicable);
+        assert!(serde_json::from_str::<GamesBack>("\"X\"").is_err());
+        assert_eq!(GamesBack::Eliminated.to_string(), "E");
+
+        assert_eq!(Pct(Some(1.0)).to_string(), "1.000");
+        assert_eq!(Pct(Some(0.0)).to_string(), ".000");
+        assert_eq!(serde_json::from_str::<Pct>("\".---\"").unwrap(), Pct(None));
+        assert_eq!(
+            serde_json::from_str::<ClinchNumber>("\"7\"").unwrap(),
+            ClinchNumber::Remaining(7)
+        );
+        assert_eq!(
+            serde_json::from_str::<ClinchNumber>("\"-\"").unwrap(),
+            ClinchNumber::NotApplicable
+        );
+        assert!(serde_json::from_str::<ClinchNumber>("\"X\"").is_err());
+        assert!(serde_json::from_str::<ClinchNumber>("\"-1\"").is_err());
+    }
+}
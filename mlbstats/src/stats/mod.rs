@@ -1,2 +1,3 @@
-pub mod types;
+pub mod player_stats;
 pub mod stats_leaders;
+pub mod types;
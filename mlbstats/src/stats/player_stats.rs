@@ -0,0 +1,380 @@
+use serde::{Deserialize, Serialize};
+
+use super::types::{LeagueLeaderTypes, PlayerPool, SortOrder, StatGroup, StatType};
+use crate::{
+    fields::Fields,
+    games::GameTypes,
+    meta::DisplayName,
+    params::QueryParams,
+    players::{Player, PlayerPosition, Position},
+    sports::Sport,
+    types::IdNameLink,
+};
+
+/// Query for the stats endpoint, built up from a stat group and narrowed with the builder methods
+#[derive(Debug, Clone)]
+pub struct StatsQuery {
+    stat_type: StatType,
+    group: StatGroup,
+    sport: Sport,
+    league_id: Option<Vec<u32>>,
+    player_pool: Option<PlayerPool>,
+    position: Option<Position>,
+    team_id: Option<u32>,
+    season: Option<String>,
+    start_date: Option<String>,
+    end_date: Option<String>,
+    game_type: Option<GameTypes>,
+    sort_stat: Option<LeagueLeaderTypes>,
+    order: Option<SortOrder>,
+    offset: Option<u32>,
+    limit: Option<u32>,
+    sit_codes: Vec<String>,
+    fields: Option<Fields>,
+}
+
+impl StatsQuery {
+    /// Creates a query for season stats of the provided group for MLB players
+    pub fn new(group: StatGroup) -> Self {
+        Self {
+            stat_type: StatType::Season,
+            group,
+            sport: Sport::MLB,
+            league_id: None,
+            player_pool: None,
+            position: None,
+            team_id: None,
+            season: None,
+            start_date: None,
+            end_date: None,
+            game_type: None,
+            sort_stat: None,
+            order: None,
+            offset: None,
+            limit: None,
+            sit_codes: vec![],
+            fields: None,
+        }
+    }
+
+    /// Type of stats to return, defaults to StatType::Season
+    pub fn stat_type(mut self, stat_type: StatType) -> Self {
+        self.stat_type = stat_type;
+        self
+    }
+
+    pub fn sport(mut self, sport: Sport) -> Self {
+        self.sport = sport;
+        self
+    }
+
+    pub fn leagues(mut self, league_id: Vec<u32>) -> Self {
+        self.league_id = Some(league_id);
+        self
+    }
+
+    /// Pool of players to include (e.g. only qualified players)
+    pub fn player_pool(mut self, player_pool: PlayerPool) -> Self {
+        self.player_pool = Some(player_pool);
+        self
+    }
+
+    pub fn position(mut self, position: Position) -> Self {
+        self.position = Some(position);
+        self
+    }
+
+    pub fn team(mut self, team_id: u32) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    /// Single season to return stats for
+    pub fn season(mut self, season: u32) -> Self {
+        self.season = Some(season.to_string());
+        self
+    }
+
+    /// Stats accumulated from the start of the first season through the end of the last season.
+    /// Uses the byDateRange stat type so the range is aggregated into a single line per player.
+    pub fn seasons(mut self, first: u32, last: u32) -> Self {
+        self.stat_type = StatType::ByDateRange;
+        self.season = None;
+        self.start_date = Some(format!("01/01/{}", first));
+        self.end_date = Some(format!("12/31/{}", last));
+        self
+    }
+
+    pub fn game_type(mut self, game_type: GameTypes) -> Self {
+        self.game_type = Some(game_type);
+        self
+    }
+
+    /// Stat to sort the results by
+    pub fn sort_stat(mut self, sort_stat: LeagueLeaderTypes) -> Self {
+        self.sort_stat = Some(sort_stat);
+        self
+    }
+
+    pub fn order(mut self, order: SortOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Number of results to skip, used with limit to page through results
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Maximum number of results to return
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Situational splits to return (e.g. "vl" for versus left handed pitchers), the available
+    /// codes can be found at https://statsapi.mlb.com/api/v1/situationCodes
+    pub fn sit_codes(mut self, sit_codes: Vec<String>) -> Self {
+        self.sit_codes = sit_codes;
+        self
+    }
+
+    /// Fields to return, `Fields::of::<StatsResponse<HittingStats>>()` returns only the fields of
+    /// the typed response
+    pub fn fields(mut self, fields: Fields) -> Self {
+        self.fields = Some(fields);
+        self
+    }
+
+    /// Converts the query into the params sent to the stats endpoint
+    pub fn params(&self) -> QueryParams {
+        QueryParams {
+            stats: Some(self.stat_type.clone()),
+            group: Some(self.group.clone()),
+            sport_id: self.sport.id(),
+            league_id: self.league_id.clone(),
+            player_pool: self.player_pool.clone(),
+            position: self.position,
+            team_id: self.team_id.map(|id| vec![id]),
+            season: self.season.clone(),
+            start_date: self.start_date.clone(),
+            end_date: self.end_date.clone(),
+            game_type: self.game_type.clone().map(|game_type| vec![game_type]),
+            sort_stat: self.sort_stat.clone(),
+            order: self.order.clone(),
+            offset: self.offset,
+            limit: self.limit,
+            sit_codes: (!self.sit_codes.is_empty()).then(|| self.sit_codes.join(",")),
+            fields: self.fields.clone(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(
+    rename_all = "camelCase",
+    default,
+    bound(deserialize = "S: Deserialize<'de> + Default")
+)]
+pub struct StatsResponse<S> {
+    /// One entry per stat type and group requested
+    pub stats: Vec<PlayerStats<S>>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+impl<S> StatsResponse<S> {
+    /// Returns the stat rows of every stat type and group in the response
+    pub fn splits(&self) -> Vec<&StatSplit<S>> {
+        self.stats.iter().flat_map(|s| s.splits.iter()).collect()
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(
+    rename_all = "camelCase",
+    default,
+    bound(deserialize = "S: Deserialize<'de> + Default")
+)]
+pub struct PlayerStats<S> {
+    #[serde(rename = "type")]
+    pub kind: DisplayName,
+    pub group: DisplayName,
+    /// Number of rows matching the query, regardless of limit
+    pub total_splits: u32,
+    pub player_pool: String,
+    pub splits: Vec<StatSplit<S>>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(
+    rename_all = "camelCase",
+    default,
+    bound(deserialize = "S: Deserialize<'de> + Default")
+)]
+pub struct StatSplit<S> {
+    /// Season the stats come from, empty for stats spanning seasons
+    pub season: String,
+    pub stat: S,
+    pub team: IdNameLink,
+    pub player: Player,
+    pub league: IdNameLink,
+    pub sport: IdNameLink,
+    pub game_type: GameTypes,
+    /// Number of teams the player played on to accumulate the stats
+    pub num_teams: u32,
+    /// Rank of the row based on the sort stat
+    pub rank: u32,
+    pub position: PlayerPosition,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Stat line returned for the hitting group
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct HittingStats {
+    pub games_played: u32,
+    pub ground_outs: u32,
+    pub air_outs: u32,
+    pub runs: u32,
+    pub doubles: u32,
+    pub triples: u32,
+    pub home_runs: u32,
+    pub strike_outs: u32,
+    pub base_on_balls: u32,
+    pub intentional_walks: u32,
+    pub hits: u32,
+    pub hit_by_pitch: u32,
+    /// Batting average (e.g. .300)
+    pub avg: String,
+    pub at_bats: u32,
+    pub obp: String,
+    pub slg: String,
+    pub ops: String,
+    pub caught_stealing: u32,
+    pub stolen_bases: u32,
+    pub stolen_base_percentage: String,
+    pub ground_into_double_play: u32,
+    pub number_of_pitches: u32,
+    pub plate_appearances: u32,
+    pub total_bases: u32,
+    pub rbi: u32,
+    pub left_on_base: u32,
+    pub sac_bunts: u32,
+    pub sac_flies: u32,
+    pub babip: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Stat line returned for the pitching group
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct PitchingStats {
+    pub games_played: u32,
+    pub games_started: u32,
+    pub hits: u32,
+    pub runs: u32,
+    pub home_runs: u32,
+    pub strike_outs: u32,
+    pub base_on_balls: u32,
+    /// Earned run average (e.g. 3.20)
+    pub era: String,
+    /// Innings pitched where the decimal is outs (e.g. 200.1)
+    pub innings_pitched: String,
+    pub wins: u32,
+    pub losses: u32,
+    pub saves: u32,
+    pub save_opportunities: u32,
+    pub holds: u32,
+    pub blown_saves: u32,
+    pub earned_runs: u32,
+    pub whip: String,
+    pub batters_faced: u32,
+    pub outs: u32,
+    pub complete_games: u32,
+    pub shutouts: u32,
+    pub number_of_pitches: u32,
+    pub strike_percentage: String,
+    pub strikeout_walk_ratio: String,
+    pub strikeouts_per9_inn: String,
+    pub walks_per9_inn: String,
+    pub hits_per9_inn: String,
+    pub home_runs_per9: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Stat line returned for the fielding group
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct FieldingStats {
+    pub games_played: u32,
+    pub games_started: u32,
+    pub assists: u32,
+    pub put_outs: u32,
+    pub errors: u32,
+    pub chances: u32,
+    pub fielding: String,
+    pub innings: String,
+    pub double_plays: u32,
+    pub triple_plays: u32,
+    pub throwing_errors: u32,
+    pub range_factor_per_game: String,
+    pub range_factor_per9_inn: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn stats_query_params() {
+        let query = StatsQuery::new(StatGroup::Hitting)
+            .player_pool(PlayerPool::Qualified)
+            .position(Position::Shortstop)
+            .seasons(2020, 2023)
+            .sort_stat(LeagueLeaderTypes::OnBasePlusSlugging)
+            .order(SortOrder::Desc)
+            .limit(10)
+            .sit_codes(vec!["vl".into(), "h".into()]);
+        let params = serde_url_params::to_string(&query.params()).unwrap();
+        assert_eq!(
+            params,
+            "sportId=1&limit=10&stats=byDateRange&group=hitting&playerPool=QUALIFIED&position=SS\
+             &sortStat=onBasePlusSlugging&order=desc&sitCodes=vl%2Ch\
+             &startDate=01%2F01%2F2020&endDate=12%2F31%2F2023"
+        );
+    }
+
+    #[test]
+    fn deserialize_hitting_stats() {
+        let stats: StatsResponse<HittingStats> =
+            serde_json::from_str(include_str!("../../tests/fixtures/stats_hitting.json")).unwrap();
+        assert_eq!(stats.stats[0].total_splits, 2);
+        let splits = stats.splits();
+        assert_eq!(splits[0].player.full_name, "Corey Seager");
+        assert_eq!(splits[0].stat.ops, "1.013");
+        assert_eq!(splits[1].stat.home_runs, 31);
+        assert_eq!(splits[1].position.abbreviation, "SS");
+    }
+}
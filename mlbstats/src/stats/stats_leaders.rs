@@ -1,14 +1,18 @@
 use super::types::LeagueLeaderTypes;
 use crate::players::Player;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct StatsLeadersResponse {
     pub league_leaders: Vec<LeaderCategory>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LeaderCategory {
     /// Stat category for the leaders
@@ -17,9 +21,13 @@ pub struct LeaderCategory {
     pub season: Option<String>,
     /// Leaders in the category
     pub leaders: Vec<Leader>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Leader {
     /// Rank amoung other leaders
@@ -34,4 +42,8 @@ pub struct Leader {
     pub season: String,
     /// Number of teams the player played on to accumlate the stat
     pub num_teams: u32,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
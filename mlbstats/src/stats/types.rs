@@ -77,7 +77,7 @@ pub enum LeagueLeaderTypes {
 }
 
 /// Common parameter for stat queries
-#[derive(Serialize, Deserialize, Debug, Clone)]
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub enum StatGroup {
     Catching,
@@ -89,3 +89,40 @@ pub enum StatGroup {
     Streak,
     Team,
 }
+
+/// Type of stats to return from the stats endpoint
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub enum StatType {
+    #[default]
+    Season,
+    Career,
+    YearByYear,
+    ByDateRange,
+    GameLog,
+    StatSplits,
+    LastXGames,
+    ByMonth,
+    HomeAndAway,
+    VsTeam,
+}
+
+/// Pool of players considered by the stats endpoint
+#[derive(Serialize, Deserialize, Default, PartialEq, Debug, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PlayerPool {
+    #[default]
+    All,
+    /// Players meeting the rate stat qualification threshold
+    Qualified,
+    Rookies,
+    QualifiedRookies,
+}
+
+/// Sort direction of the stats endpoint
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
@@ -5,22 +5,30 @@ use serde::{Deserialize, Serialize};
 
 use crate::{error::MLBStatsError, types::IdNameLink};
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct TeamsResponse {
     pub teams: Vec<Team>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Team {
     pub all_star_status: String,
     pub id: u32,
     pub name: String,
     pub link: String,
+    /// Season the team information applies to
+    pub season: u32,
     pub venue: IdNameLink,
     pub team_code: String,
     pub file_code: String,
+    /// Abbreviation used on scoreboards (e.g. TEX)
+    pub abbreviation: String,
     pub team_name: String,
     pub location_name: String,
     pub first_year_of_play: String,
@@ -31,11 +39,194 @@ pub struct Team {
     pub franchise_name: String,
     pub club_name: String,
     pub active: bool,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+/// Lookup table over the teams returned by v1/teams. Unlike [`MLBTeam`] it covers every sport
+/// and season the API knows about, including relocated and historical clubs.
+#[derive(Default, Debug, Clone)]
+pub struct TeamRegistry {
+    teams: Vec<Team>,
+}
+
+impl TeamRegistry {
+    pub fn new(teams: Vec<Team>) -> Self {
+        TeamRegistry { teams }
+    }
+
+    pub fn teams(&self) -> &[Team] {
+        &self.teams
+    }
+
+    pub fn by_id(&self, id: u32) -> Option<&Team> {
+        self.teams.iter().find(|t| t.id == id)
+    }
+
+    /// Case insensitive lookup by scoreboard abbreviation (e.g. TEX)
+    pub fn by_abbreviation(&self, abbreviation: &str) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|t| t.abbreviation.eq_ignore_ascii_case(abbreviation))
+    }
+
+    /// Case insensitive lookup by team code (e.g. nya)
+    pub fn by_team_code(&self, code: &str) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|t| t.team_code.eq_ignore_ascii_case(code))
+    }
+
+    /// Case insensitive lookup by file code (e.g. nyy)
+    pub fn by_file_code(&self, code: &str) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|t| t.file_code.eq_ignore_ascii_case(code))
+    }
+
+    /// Case insensitive lookup by club name (e.g. Red Sox)
+    pub fn by_club_name(&self, name: &str) -> Option<&Team> {
+        self.teams
+            .iter()
+            .find(|t| t.club_name.eq_ignore_ascii_case(name))
+    }
+
+    /// Team matching one of the hard-coded MLB clubs
+    pub fn get(&self, team: &MLBTeam) -> Option<&Team> {
+        self.by_id(team.id())
+    }
+
+    /// Teams whose names match the query, best match first. A name matches when it equals the
+    /// query, contains it as whole words, or is within a small edit distance of it.
+    pub fn fuzzy_matches(&self, query: &str) -> Vec<&Team> {
+        self.scored(query).into_iter().map(|(_, team)| team).collect()
+    }
+
+    /// Best fuzzy match, None when nothing matches or the best match is ambiguous (e.g. Chicago)
+    pub fn fuzzy(&self, query: &str) -> Option<&Team> {
+        match self.scored(query).as_slice() {
+            [(_, team)] => Some(team),
+            [(best, team), (next, _), ..] if best < next => Some(team),
+            _ => None,
+        }
+    }
+
+    /// Resolves a team from anything a user might type: an id, abbreviation, team or file code,
+    /// one of the [`MLBTeam`] aliases, a club name, or finally a fuzzy name match
+    pub fn find(&self, query: &str) -> Option<&Team> {
+        let query = query.trim();
+        if let Ok(id) = query.parse::<u32>() {
+            return self.by_id(id);
+        }
+        self.by_abbreviation(query)
+            .or_else(|| self.by_team_code(query))
+            .or_else(|| self.by_file_code(query))
+            .or_else(|| {
+                MLBTeam::from_str(query)
+                    .ok()
+                    .and_then(|team| self.get(&team))
+            })
+            .or_else(|| self.by_club_name(query))
+            .or_else(|| self.fuzzy(query))
+    }
+
+    // Matching teams with their best score, sorted best first
+    fn scored(&self, query: &str) -> Vec<(usize, &Team)> {
+        let query = normalize(query);
+        if query.is_empty() {
+            return vec![];
+        }
+        let mut scores: Vec<(usize, &Team)> = self
+            .teams
+            .iter()
+            .filter_map(|team| {
+                team_names(team)
+                    .iter()
+                    .filter_map(|name| match_score(&query, &normalize(name)))
+                    .min()
+                    .map(|score| (score, team))
+            })
+            .collect();
+        scores.sort_by_key(|(score, _)| *score);
+        scores
+    }
+}
+
+impl From<TeamsResponse> for TeamRegistry {
+    fn from(value: TeamsResponse) -> Self {
+        TeamRegistry::new(value.teams)
+    }
 }
 
-#[derive(PartialEq, Debug, Clone)]
+fn team_names(team: &Team) -> [String; 5] {
+    [
+        team.name.clone(),
+        team.short_name.clone(),
+        team.club_name.clone(),
+        team.team_name.clone(),
+        format!("{} {}", team.franchise_name, team.club_name),
+    ]
+}
+
+// Lowercases and strips punctuation so "St. Louis" and "st louis" compare equal
+fn normalize(name: &str) -> String {
+    name.to_lowercase()
+        .chars()
+        .filter(|c| c.is_alphanumeric() || c.is_whitespace())
+        .collect::<String>()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// Lower is better: 0 for an exact match, 1 for a whole word match, 2 + the edit distance for a
+// close misspelling
+fn match_score(query: &str, name: &str) -> Option<usize> {
+    if name.is_empty() {
+        return None;
+    }
+    if query == name {
+        return Some(0);
+    }
+    if format!(" {} ", name).contains(&format!(" {} ", query)) {
+        return Some(1);
+    }
+    let distance = edit_distance(query, name);
+    (distance <= (query.chars().count() / 4).max(1)).then_some(2 + distance)
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut previous = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let current = row[j + 1];
+            row[j + 1] = if ca == *cb {
+                previous
+            } else {
+                1 + previous.min(row[j]).min(row[j + 1])
+            };
+            previous = current;
+        }
+    }
+    row[b.len()]
+}
+
+impl TryFrom<&Team> for MLBTeam {
+    type Error = MLBStatsError;
+
+    fn try_from(value: &Team) -> Result<Self, Self::Error> {
+        MLBTeam::try_from(value.id)
+    }
+}
+
+#[derive(PartialEq, Eq, Debug, Clone)]
 pub enum MLBTeam {
-    ANA,
+    ATH,
     ARI,
     ATL,
     BAL,
@@ -48,13 +239,13 @@ pub enum MLBTeam {
     DET,
     HOU,
     KC,
-    LA,
+    LAA,
+    LAD,
     MIA,
     MIL,
     MIN,
     NYM,
     NYY,
-    OAK,
     PHI,
     PIT,
     SD,
@@ -64,13 +255,22 @@ pub enum MLBTeam {
     TB,
     TEX,
     TOR,
-    WAS,
+    WSH,
 }
 
 impl MLBTeam {
+    /// Previous name of MLBTeam::LAA
+    pub const ANA: MLBTeam = MLBTeam::LAA;
+    /// Previous name of MLBTeam::LAD
+    pub const LA: MLBTeam = MLBTeam::LAD;
+    /// Previous name of MLBTeam::WSH
+    pub const WAS: MLBTeam = MLBTeam::WSH;
+    /// Previous name of MLBTeam::ATH
+    pub const OAK: MLBTeam = MLBTeam::ATH;
+
     pub fn id(&self) -> u32 {
         match &self {
-            MLBTeam::ANA => 108,
+            MLBTeam::LAA => 108,
             MLBTeam::ARI => 109,
             MLBTeam::ATL => 144,
             MLBTeam::BAL => 110,
@@ -83,13 +283,13 @@ impl MLBTeam {
             MLBTeam::DET => 116,
             MLBTeam::HOU => 117,
             MLBTeam::KC => 118,
-            MLBTeam::LA => 119,
+            MLBTeam::LAD => 119,
             MLBTeam::MIA => 146,
             MLBTeam::MIL => 158,
             MLBTeam::MIN => 142,
             MLBTeam::NYM => 121,
             MLBTeam::NYY => 147,
-            MLBTeam::OAK => 133,
+            MLBTeam::ATH => 133,
             MLBTeam::PHI => 143,
             MLBTeam::PIT => 134,
             MLBTeam::SD => 135,
@@ -99,7 +299,7 @@ impl MLBTeam {
             MLBTeam::TB => 139,
             MLBTeam::TEX => 140,
             MLBTeam::TOR => 141,
-            MLBTeam::WAS => 120,
+            MLBTeam::WSH => 120,
         }
     }
 }
@@ -109,7 +309,7 @@ impl TryFrom<u32> for MLBTeam {
 
     fn try_from(value: u32) -> Result<Self, Self::Error> {
         Ok(match value {
-            108 => MLBTeam::ANA,
+            108 => MLBTeam::LAA,
             109 => MLBTeam::ARI,
             144 => MLBTeam::ATL,
             110 => MLBTeam::BAL,
@@ -122,13 +322,13 @@ impl TryFrom<u32> for MLBTeam {
             116 => MLBTeam::DET,
             117 => MLBTeam::HOU,
             118 => MLBTeam::KC,
-            119 => MLBTeam::LA,
+            119 => MLBTeam::LAD,
             146 => MLBTeam::MIA,
             158 => MLBTeam::MIL,
             142 => MLBTeam::MIN,
             121 => MLBTeam::NYM,
             147 => MLBTeam::NYY,
-            133 => MLBTeam::OAK,
+            133 => MLBTeam::ATH,
             143 => MLBTeam::PHI,
             134 => MLBTeam::PIT,
             135 => MLBTeam::SD,
@@ -138,8 +338,8 @@ impl TryFrom<u32> for MLBTeam {
             139 => MLBTeam::TB,
             140 => MLBTeam::TEX,
             141 => MLBTeam::TOR,
-            120 => MLBTeam::WAS,
-            _ => return Err(MLBStatsError::MLBTeamConversionError),
+            120 => MLBTeam::WSH,
+            _ => return Err(MLBStatsError::MLBTeamConversionError(value)),
         })
     }
 }
@@ -149,8 +349,8 @@ impl FromStr for MLBTeam {
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         Ok(match s.to_lowercase().as_str() {
-            "ana" => MLBTeam::ANA,
-            "ari" => MLBTeam::ARI,
+            "laa" | "ana" => MLBTeam::LAA,
+            "ari" | "az" => MLBTeam::ARI,
             "atl" => MLBTeam::ATL,
             "bal" => MLBTeam::BAL,
             "bos" => MLBTeam::BOS,
@@ -158,32 +358,38 @@ impl FromStr for MLBTeam {
             "cin" => MLBTeam::CIN,
             "cle" => MLBTeam::CLE,
             "col" => MLBTeam::COL,
-            "cws" => MLBTeam::CWS,
+            "cws" | "chw" => MLBTeam::CWS,
             "det" => MLBTeam::DET,
             "hou" => MLBTeam::HOU,
-            "kc"  => MLBTeam::KC,
-            "la"  => MLBTeam::LA,
+            "kc" | "kcr" => MLBTeam::KC,
+            "lad" | "la" => MLBTeam::LAD,
             "mia" => MLBTeam::MIA,
             "mil" => MLBTeam::MIL,
             "min" => MLBTeam::MIN,
             "nym" => MLBTeam::NYM,
             "nyy" => MLBTeam::NYY,
-            "oak" => MLBTeam::OAK,
+            "ath" | "oak" => MLBTeam::ATH,
             "phi" => MLBTeam::PHI,
             "pit" => MLBTeam::PIT,
-            "sd"  => MLBTeam::SD,
+            "sd" | "sdp" => MLBTeam::SD,
             "sea" => MLBTeam::SEA,
-            "sf"  => MLBTeam::SF,
+            "sf" | "sfg" => MLBTeam::SF,
             "stl" => MLBTeam::STL,
-            "tb"  => MLBTeam::TB,
+            "tb" | "tbr" => MLBTeam::TB,
             "tex" => MLBTeam::TEX,
             "tor" => MLBTeam::TOR,
-            "was" => MLBTeam::WAS,
-            _ => return Err(MLBStatsError::MLBConversionError("FromStr".into(), s.into())),
+            "wsh" | "was" => MLBTeam::WSH,
+            _ => return Err(MLBStatsError::MLBConversionError("MLBTeam".into(), s.into())),
         })
     }
 }
 
+impl From<MLBTeam> for u32 {
+    fn from(value: MLBTeam) -> Self {
+        value.id()
+    }
+}
+
 impl Serialize for MLBTeam {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -199,11 +405,63 @@ mod test {
     use serde_test::{Token, assert_ser_tokens};
 
     #[test]
+    #[allow(clippy::bool_assert_comparison)]
     fn mlb_team_conversions() {
         assert_eq!(MLBTeam::try_from(108).unwrap(), MLBTeam::ANA);
         assert_eq!(MLBTeam::try_from(1000).is_err(), true);
     }
     
+    #[test]
+    fn mlb_team_aliases() {
+        for (alias, team) in [
+            ("LAA", MLBTeam::LAA),
+            ("ana", MLBTeam::ANA),
+            ("LAD", MLBTeam::LA),
+            ("WSH", MLBTeam::WAS),
+            ("ATH", MLBTeam::OAK),
+            ("CHW", MLBTeam::CWS),
+            ("KCR", MLBTeam::KC),
+            ("SDP", MLBTeam::SD),
+            ("SFG", MLBTeam::SF),
+            ("TBR", MLBTeam::TB),
+            ("AZ", MLBTeam::ARI),
+        ] {
+            assert_eq!(MLBTeam::from_str(alias).unwrap(), team);
+        }
+        assert_eq!(
+            MLBTeam::try_from(1000).unwrap_err().to_string(),
+            "failed to convert team: 1000 is not the id of a current MLB team, use TeamRegistry \
+             for other teams"
+        );
+    }
+
+    #[test]
+    fn team_registry_lookups() {
+        let teams: TeamsResponse =
+            serde_json::from_str(include_str!("../tests/fixtures/teams.json")).unwrap();
+        let registry = TeamRegistry::from(teams);
+
+        assert_eq!(registry.by_id(140).unwrap().name, "Texas Rangers");
+        assert_eq!(registry.by_abbreviation("az").unwrap().id, 109);
+        assert_eq!(registry.by_team_code("NYA").unwrap().id, 147);
+        assert_eq!(registry.by_file_code("nyy").unwrap().id, 147);
+        assert_eq!(registry.by_club_name("red sox").unwrap().id, 111);
+        assert_eq!(registry.get(&MLBTeam::OAK).unwrap().abbreviation, "ATH");
+        assert_eq!(
+            MLBTeam::try_from(registry.by_id(133).unwrap()).unwrap(),
+            MLBTeam::ATH
+        );
+
+        assert_eq!(registry.find("137").unwrap().id, 137);
+        assert_eq!(registry.find("KCR").unwrap().id, 118);
+        assert_eq!(registry.find("Athletics").unwrap().id, 133);
+        assert_eq!(registry.find("st louis cardinals").unwrap().id, 138);
+        assert_eq!(registry.find("Yankes").unwrap().id, 147);
+        assert!(registry.find("Chicago").is_none());
+        assert_eq!(registry.fuzzy_matches("Chicago").len(), 2);
+        assert!(registry.find("Montreal Expos").is_none());
+    }
+
     #[test]
     fn serialize_mlbteam() {
         let tex = MLBTeam::TEX;
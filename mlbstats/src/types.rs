@@ -1,22 +1,182 @@
 #![allow(non_snake_case)]
-use serde::Deserialize;
+use std::fmt::Display;
 
-#[derive(Deserialize, Default, Debug, Clone)]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Fields returned by the API that aren't modeled by the crate. With the unknown_fields feature
+/// every model captures them in a flattened `unknown_fields` field, declared last.
+#[cfg(feature = "unknown_fields")]
+pub type UnknownFields = std::collections::HashMap<String, serde_json::Value>;
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct Record {
     pub wins: u32,
     pub losses: u32,
     pub ties: Option<u32>,
-    pub pct: String,
+    pub pct: Pct,
     #[serde(rename = "type")]
     pub kind: Option<String>,
     pub division: Option<IdNameLink>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: UnknownFields,
 }
 
-#[derive(Deserialize, Default, Debug, Clone)]
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
 #[serde(rename_all = "camelCase", default)]
 pub struct IdNameLink {
     pub id: u32,
     pub name: Option<String>,
     pub link: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: UnknownFields,
+}
+
+/// Winning percentage, sent by the API as a string (e.g. .543). None when the API has no
+/// percentage, e.g. before a team has played.
+#[derive(PartialEq, PartialOrd, Default, Debug, Clone, Copy)]
+pub struct Pct(pub Option<f64>);
+
+impl Pct {
+    pub fn value(&self) -> Option<f64> {
+        self.0
+    }
+}
+
+impl Display for Pct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.0 {
+            Some(pct) => {
+                let pct = format!("{:.3}", pct);
+                write!(f, "{}", pct.strip_prefix('0').unwrap_or(&pct))
+            }
+            None => write!(f, ".---"),
+        }
+    }
+}
+
+impl Serialize for Pct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Some(_) => serializer.serialize_str(&self.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Pct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let pct = Option::<String>::deserialize(deserializer)?;
+        Ok(Pct(pct.and_then(|pct| pct.parse().ok())))
+    }
+}
+
+// Numbers the API sends as strings (e.g. ranks), an empty or unparsable value deserializes to None
+pub(crate) mod optional_number {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(number: &Option<u32>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match number {
+            Some(number) => serializer.serialize_str(&number.to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<String>::deserialize(deserializer)?.and_then(|number| number.parse().ok()))
+    }
+}
+
+// Dates are parsed from their string form so that an empty date deserializes to None instead of
+// failing the whole response. This also keeps models holding dates traceable by Fields::of.
+pub(crate) mod optional_date {
+    use chrono::NaiveDate;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &Option<NaiveDate>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => serializer.serialize_str(&date.format("%Y-%m-%d").to_string()),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)?.as_deref() {
+            None | Some("") => Ok(None),
+            Some(date) => NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(Some)
+                .map_err(Error::custom),
+        }
+    }
+}
+
+// Same as optional_date, an empty date deserializes to the default date like a missing one
+pub(crate) mod date {
+    use chrono::NaiveDate;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &NaiveDate, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(&date.format("%Y-%m-%d").to_string())
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<NaiveDate, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Ok(super::optional_date::deserialize(deserializer)?.unwrap_or_default())
+    }
+}
+
+pub(crate) mod optional_datetime {
+    use chrono::{DateTime, SecondsFormat, Utc};
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(date: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match date {
+            Some(date) => {
+                serializer.serialize_str(&date.to_rfc3339_opts(SecondsFormat::Secs, true))
+            }
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Option::<String>::deserialize(deserializer)?.as_deref() {
+            None | Some("") => Ok(None),
+            Some(date) => DateTime::parse_from_rfc3339(date)
+                .map(|date| Some(date.with_timezone(&Utc)))
+                .map_err(Error::custom),
+        }
+    }
 }
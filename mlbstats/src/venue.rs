@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct Venue {
+    pub id: u32,
+    pub name: String,
+    pub link: String,
+    /// Requires the timezone hydration (e.g. venue(timezone) on the schedule)
+    pub time_zone: Option<VenueTimeZone>,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
+
+#[derive(Serialize, Deserialize, Default, Debug, Clone)]
+#[serde(rename_all = "camelCase", default)]
+pub struct VenueTimeZone {
+    /// IANA name of the time zone (e.g. America/Chicago)
+    pub id: String,
+    /// Current offset from UTC in hours
+    pub offset: i32,
+    /// Offset from UTC in hours when the game starts, accounts for daylight saving time
+    pub offset_at_game_time: i32,
+    /// Abbreviation of the time zone (e.g. CDT)
+    pub tz: String,
+
+    #[cfg(feature = "unknown_fields")]
+    #[serde(flatten)]
+    pub unknown_fields: crate::types::UnknownFields,
+}
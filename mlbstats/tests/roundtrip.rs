@@ -0,0 +1,272 @@
+//! Round trips the recorded fixtures through the models. Every field a model serializes must
+//! match the fixture value it was deserialized from, and serializing a model read back from its
+//! own output must be lossless.
+//!
+//! Fixture fields the models drop are checked by `unmodeled_fields` against the allowlist in
+//! fixtures/unmodeled_fields.txt, so a field newly dropped by a model fails the test. Run with
+//! MLBSTATS_STRICT=1 to fail on every unmodeled field, allowlisted or not:
+//! `MLBSTATS_STRICT=1 cargo test --test roundtrip -- --nocapture`
+
+use mlbstats::{
+    attendance::AttendanceResponse,
+    jobs::JobsResponse,
+    meta::{GameTypeMeta, StandingsTypeMeta},
+    postseason::{PostseasonBracket, PostseasonResponse},
+    roster::RosterResponse,
+    schedule::ScheduleResponse,
+    standings::StandingsResponse,
+    stats::player_stats::{HittingStats, StatsResponse},
+    teams::TeamsResponse,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+
+macro_rules! fixture {
+    ($path:literal) => {
+        include_str!(concat!("fixtures/", $path))
+    };
+}
+
+fn round_trip<T>(fixture: &str) -> T
+where
+    T: DeserializeOwned + Serialize,
+{
+    let original: Value = serde_json::from_str(fixture).unwrap();
+    let model: T = serde_json::from_str(fixture).unwrap();
+
+    let serialized = serde_json::to_value(&model).unwrap();
+    assert_matches_fixture(&original, &serialized, "$");
+
+    let reparsed: T = serde_json::from_value(serialized.clone()).unwrap();
+    assert_eq!(serde_json::to_value(&reparsed).unwrap(), serialized);
+    model
+}
+
+// Fields missing from the fixture are filled with defaults by the models so only the fields the
+// fixture provides are compared
+fn assert_matches_fixture(original: &Value, serialized: &Value, path: &str) {
+    match (original, serialized) {
+        (Value::Object(original), Value::Object(serialized)) => {
+            for (key, value) in serialized {
+                if let Some(original) = original.get(key) {
+                    assert_matches_fixture(original, value, &format!("{}.{}", path, key));
+                }
+            }
+        }
+        (Value::Array(original), Value::Array(serialized)) => {
+            assert_eq!(original.len(), serialized.len(), "length of {}", path);
+            for (i, (original, value)) in original.iter().zip(serialized).enumerate() {
+                assert_matches_fixture(original, value, &format!("{}[{}]", path, i));
+            }
+        }
+        (original, serialized) => assert_eq!(original, serialized, "value of {}", path),
+    }
+}
+
+#[test]
+fn schedule() {
+    let schedule: ScheduleResponse = round_trip(fixture!("schedule_double_header.json"));
+    assert_eq!(schedule.total_games, 3);
+
+    let schedule: ScheduleResponse = round_trip(fixture!("schedule_live.json"));
+    let game = &schedule.games()[0];
+    assert_eq!(game.linescore.innings.len(), 6);
+    assert_eq!(game.scoring_plays[0].result.event, "Home Run");
+
+    let schedule: ScheduleResponse = round_trip(fixture!("schedule_seasons.json"));
+    assert_eq!(schedule.total_games, 8);
+}
+
+#[test]
+fn standings() {
+    let standings: StandingsResponse = round_trip(fixture!("standings_al_2023.json"));
+    assert_eq!(standings.records.len(), 3);
+}
+
+#[test]
+fn teams() {
+    let teams: TeamsResponse = round_trip(fixture!("teams.json"));
+    assert_eq!(teams.teams.len(), 30);
+
+    round_trip::<TeamsResponse>(fixture!("team_history_120.json"));
+    round_trip::<TeamsResponse>(fixture!("team_history_140.json"));
+}
+
+#[test]
+fn roster() {
+    let roster: RosterResponse = round_trip(fixture!("roster_tex.json"));
+    assert_eq!(roster.roster.len(), 17);
+}
+
+#[test]
+fn stats() {
+    round_trip::<StatsResponse<HittingStats>>(fixture!("stats_hitting.json"));
+}
+
+#[test]
+fn postseason() {
+    let postseason: PostseasonResponse = round_trip(fixture!("postseason_series.json"));
+
+    // the bracket is built by the crate so it's compared against its own output
+    let bracket = PostseasonBracket::from(postseason);
+    let serialized = serde_json::to_string(&bracket).unwrap();
+    round_trip::<PostseasonBracket>(&serialized);
+}
+
+#[test]
+fn attendance() {
+    round_trip::<AttendanceResponse>(fixture!("attendance.json"));
+}
+
+#[test]
+fn jobs() {
+    round_trip::<JobsResponse>(fixture!("jobs_umpires.json"));
+}
+
+#[test]
+fn meta() {
+    round_trip::<Vec<GameTypeMeta>>(fixture!("meta/gameTypes.json"));
+    round_trip::<Vec<StandingsTypeMeta>>(fixture!("meta/standingsTypes.json"));
+}
+
+// Captured unknown fields are serialized back out, so the report is only meaningful without the
+// unknown_fields feature
+#[cfg(not(feature = "unknown_fields"))]
+mod report {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    // Collects the paths of fields present in the fixture but missing from the serialized model,
+    // array indices are collapsed so each field is reported once
+    fn collect_unmodeled(
+        original: &Value,
+        serialized: &Value,
+        path: &str,
+        found: &mut BTreeSet<String>,
+    ) {
+        match (original, serialized) {
+            (Value::Object(original), Value::Object(serialized)) => {
+                for (key, value) in original {
+                    let path = format!("{}.{}", path, key);
+                    match serialized.get(key) {
+                        Some(serialized) => collect_unmodeled(value, serialized, &path, found),
+                        None => {
+                            found.insert(path);
+                        }
+                    }
+                }
+            }
+            (Value::Array(original), Value::Array(serialized)) => {
+                for (original, value) in original.iter().zip(serialized) {
+                    collect_unmodeled(original, value, &format!("{}[]", path), found);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn unmodeled<T>(fixture: &str) -> BTreeSet<String>
+    where
+        T: DeserializeOwned + Serialize,
+    {
+        let original: Value = serde_json::from_str(fixture).unwrap();
+        let model: T = serde_json::from_str(fixture).unwrap();
+        let mut found = BTreeSet::new();
+        collect_unmodeled(
+            &original,
+            &serde_json::to_value(&model).unwrap(),
+            "$",
+            &mut found,
+        );
+        found
+    }
+
+    #[test]
+    fn unmodeled_fields() {
+        let reports = [
+            (
+                "schedule_double_header.json",
+                unmodeled::<ScheduleResponse>(fixture!("schedule_double_header.json")),
+            ),
+            (
+                "schedule_live.json",
+                unmodeled::<ScheduleResponse>(fixture!("schedule_live.json")),
+            ),
+            (
+                "schedule_seasons.json",
+                unmodeled::<ScheduleResponse>(fixture!("schedule_seasons.json")),
+            ),
+            (
+                "standings_al_2023.json",
+                unmodeled::<StandingsResponse>(fixture!("standings_al_2023.json")),
+            ),
+            (
+                "teams.json",
+                unmodeled::<TeamsResponse>(fixture!("teams.json")),
+            ),
+            (
+                "roster_tex.json",
+                unmodeled::<RosterResponse>(fixture!("roster_tex.json")),
+            ),
+            (
+                "stats_hitting.json",
+                unmodeled::<StatsResponse<HittingStats>>(fixture!("stats_hitting.json")),
+            ),
+            (
+                "postseason_series.json",
+                unmodeled::<PostseasonResponse>(fixture!("postseason_series.json")),
+            ),
+            (
+                "attendance.json",
+                unmodeled::<AttendanceResponse>(fixture!("attendance.json")),
+            ),
+            (
+                "jobs_umpires.json",
+                unmodeled::<JobsResponse>(fixture!("jobs_umpires.json")),
+            ),
+        ];
+
+        let allowed: BTreeSet<String> = fixture!("unmodeled_fields.txt")
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .map(String::from)
+            .collect();
+
+        let mut found = BTreeSet::new();
+        for (fixture, fields) in &reports {
+            for field in fields {
+                println!("{}: {}", fixture, field);
+                found.insert(format!("{}: {}", fixture, field));
+            }
+        }
+        println!("{} unmodeled fields", found.len());
+
+        let dropped: Vec<&String> = found.difference(&allowed).collect();
+        assert!(
+            dropped.is_empty(),
+            "fields dropped by the models, model them or add them to unmodeled_fields.txt: {:#?}",
+            dropped
+        );
+        let modeled: Vec<&String> = allowed.difference(&found).collect();
+        assert!(
+            modeled.is_empty(),
+            "fields in unmodeled_fields.txt are modeled now, remove them: {:#?}",
+            modeled
+        );
+
+        if std::env::var_os("MLBSTATS_STRICT").is_some() {
+            assert_eq!(found.len(), 0, "fixtures contain unmodeled fields");
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "unknown_fields")]
+fn captures_unknown_fields() {
+    let schedule: ScheduleResponse = round_trip(fixture!("schedule_live.json"));
+    let game = &schedule.games()[0];
+    assert_eq!(game.unknown_fields["isTie"], Value::Bool(false));
+    assert!(!game.linescore.offense.unknown_fields.is_empty());
+    assert!(schedule.unknown_fields.contains_key("copyright"));
+}